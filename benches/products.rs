@@ -0,0 +1,35 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use matrix_simple::Matrix;
+use num_rational::Ratio;
+
+fn square<T : Clone+Default, F : Fn(usize, usize) -> T>(n : usize, f : F) -> Matrix<T> {
+    Matrix::from((0..n).map(|i| (0..n).map(|j| f(i, j)).collect()).collect::<Vec<Vec<T>>>())
+}
+
+fn products(c : &mut Criterion) {
+    let a = square(128, |i, j| (i * 7 + j * 3) as f64 / 11.0);
+    let b = square(128, |i, j| (i + 2 * j) as f64 - 64.0);
+    c.bench_function("f64 128x128 product", |bench| bench.iter(|| black_box(&a) * black_box(&b)));
+    c.bench_function("f64 128x128 sum", |bench| bench.iter(|| black_box(a.clone()) + black_box(b.clone())));
+
+    let a = square(32, |i, j| Ratio::new(i as i64 + 1, j as i64 + 1));
+    let b = square(32, |i, j| Ratio::new(j as i64 - 3, i as i64 + 2));
+    c.bench_function("Ratio<i64> 32x32 product", |bench| bench.iter(|| black_box(&a) * black_box(&b)));
+    c.bench_function("Ratio<i64> 32x32 mul_ref", |bench| bench.iter(|| black_box(&a).mul_ref(black_box(&b))));
+}
+
+#[cfg(feature = "num-bigint")]
+fn big_products(c : &mut Criterion) {
+    use num_bigint::BigInt;
+
+    let big = |i : usize, j : usize| BigInt::from(3).pow(200 + (i * j) as u32 % 50);
+    let (a, b) = (square(24, big), square(24, |i, j| big(j, i)));
+    c.bench_function("BigInt 24x24 product", |bench| bench.iter(|| black_box(&a) * black_box(&b)));
+    c.bench_function("BigInt 24x24 mul_ref", |bench| bench.iter(|| black_box(&a).mul_ref(black_box(&b))));
+}
+
+#[cfg(not(feature = "num-bigint"))]
+fn big_products(_ : &mut Criterion) {}
+
+criterion_group!(benches, products, big_products);
+criterion_main!(benches);
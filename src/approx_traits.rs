@@ -0,0 +1,68 @@
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::Matrix;
+
+// Entrywise approximate equality, so float matrices can be checked with
+// assert_relative_eq! and friends. Matrices of different shapes are never
+// equal.
+
+impl<T> Matrix<T> {
+    fn all_entries<F : Fn(&T, &T) -> bool>(&self, other : &Matrix<T>, f : F) -> bool {
+        self.rows == other.rows && self.cols == other.cols
+            && self.m.iter().flatten().zip(other.m.iter().flatten()).all(|(a, b)| f(a, b))
+    }
+}
+
+impl<T : AbsDiffEq> AbsDiffEq for Matrix<T> where T::Epsilon : Clone {
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> T::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other : &Self, epsilon : T::Epsilon) -> bool {
+        self.all_entries(other, |a, b| a.abs_diff_eq(b, epsilon.clone()))
+    }
+}
+
+impl<T : RelativeEq> RelativeEq for Matrix<T> where T::Epsilon : Clone {
+    fn default_max_relative() -> T::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(&self, other : &Self, epsilon : T::Epsilon, max_relative : T::Epsilon) -> bool {
+        self.all_entries(other, |a, b| a.relative_eq(b, epsilon.clone(), max_relative.clone()))
+    }
+}
+
+impl<T : UlpsEq> UlpsEq for Matrix<T> where T::Epsilon : Clone {
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other : &Self, epsilon : T::Epsilon, max_ulps : u32) -> bool {
+        self.all_entries(other, |a, b| a.ulps_eq(b, epsilon.clone(), max_ulps))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::{assert_abs_diff_eq, assert_relative_eq, assert_relative_ne, assert_ulps_eq};
+
+    use crate::Matrix;
+
+    #[test]
+    fn approximate_comparisons() {
+        let a = Matrix::from([[4.0f64,12.0],[12.0,37.0]]);
+        let l = a.cholesky().unwrap();
+        let mut lt = l.clone();
+        lt.transpose();
+
+        assert_relative_eq!(&l * &lt, a);
+        assert_ulps_eq!(Matrix::from([[0.1f64 + 0.2]]), Matrix::from([[0.3]]));
+        assert_abs_diff_eq!(Matrix::from([[1.0f32,2.0]]), Matrix::from([[1.05,1.95]]), epsilon = 0.1);
+
+        assert_relative_ne!(Matrix::from([[1.0f64,2.0]]), Matrix::from([[1.0,2.1]]));
+        assert_relative_ne!(Matrix::from([[1.0f64,2.0]]), Matrix::from([[1.0],[2.0]]));
+    }
+}
@@ -0,0 +1,180 @@
+use quickcheck::{Arbitrary, Gen};
+
+use crate::Matrix;
+
+// quickcheck support, for property testing algebraic identities. Generated
+// shapes grow with the generator's size: each dimension is at most
+// √size, so the default size of 100 gives matrices up to 10×10. For
+// control over the entries, generate a narrower type and cast it (e.g.
+// Matrix<i8> then .cast::<i64>() keeps products from overflowing), or use
+// arbitrary_with.
+
+impl<T> Matrix<T> {
+    // rows × cols matrix with each entry drawn from f, row by row
+    pub fn arbitrary_with<F>(g : &mut Gen, rows : usize, cols : usize, mut f : F) -> Matrix<T>
+        where F : FnMut(&mut Gen) -> T {
+        let m = (0..rows).map(|_| (0..cols).map(|_| f(g)).collect()).collect();
+        Matrix { m, rows, cols }
+    }
+
+    // Copy without row i
+    fn without_row(&self, i : usize) -> Matrix<T> where T : Clone {
+        let mut m = self.m.clone();
+        m.remove(i);
+        Matrix { m, rows : self.rows - 1, cols : self.cols }
+    }
+
+    // Copy without column j
+    fn without_col(&self, j : usize) -> Matrix<T> where T : Clone {
+        let mut m = self.m.clone();
+        for row in m.iter_mut() {
+            row.remove(j);
+        }
+        Matrix { m, rows : self.rows, cols : self.cols - 1 }
+    }
+}
+
+fn dimension(g : &mut Gen) -> usize {
+    let bound = (g.size() as f64).sqrt() as usize;
+    usize::arbitrary(g) % (bound + 1)
+}
+
+impl<T : Arbitrary> Arbitrary for Matrix<T> {
+    fn arbitrary(g : &mut Gen) -> Self {
+        let (rows, cols) = (dimension(g), dimension(g));
+        Matrix::arbitrary_with(g, rows, cols, T::arbitrary)
+    }
+
+    // Shrinks the shape first, dropping one row or column at a time, then
+    // the entries one at a time
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let a = self.clone();
+        let rows = (0..a.rows).rev().map({ let a = a.clone(); move |i| a.without_row(i) });
+        let cols = (0..a.cols).rev().map({ let a = a.clone(); move |j| a.without_col(j) });
+        let entries = (0..a.rows * a.cols).flat_map(move |k| {
+            let (i, j) = (k / a.cols, k % a.cols);
+            let a = a.clone();
+            a.m[i][j].shrink().map(move |x| {
+                let mut b = a.clone();
+                b.m[i][j] = x;
+                b
+            })
+        });
+
+        Box::new(rows.chain(cols).chain(entries))
+    }
+}
+
+// Two matrices of the same shape, for element-wise identities like
+// a + b == b + a
+#[derive(Clone,Debug)]
+pub struct SameShape<T>(pub Matrix<T>, pub Matrix<T>);
+
+// Two matrices which can be multiplied, a.cols() == b.rows()
+#[derive(Clone,Debug)]
+pub struct Conformable<T>(pub Matrix<T>, pub Matrix<T>);
+
+#[derive(Clone,Debug)]
+pub struct Square<T>(pub Matrix<T>);
+
+impl<T : Arbitrary> Arbitrary for SameShape<T> {
+    fn arbitrary(g : &mut Gen) -> Self {
+        let (rows, cols) = (dimension(g), dimension(g));
+        SameShape(Matrix::arbitrary_with(g, rows, cols, T::arbitrary),
+                  Matrix::arbitrary_with(g, rows, cols, T::arbitrary))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let SameShape(a, b) = self.clone();
+        let rows = (0..a.rows).rev().map({
+            let (a, b) = (a.clone(), b.clone());
+            move |i| SameShape(a.without_row(i), b.without_row(i))
+        });
+        let cols = (0..a.cols).rev().map(move |j| SameShape(a.without_col(j), b.without_col(j)));
+
+        Box::new(rows.chain(cols))
+    }
+}
+
+impl<T : Arbitrary> Arbitrary for Conformable<T> {
+    fn arbitrary(g : &mut Gen) -> Self {
+        let (n, k, m) = (dimension(g), dimension(g), dimension(g));
+        Conformable(Matrix::arbitrary_with(g, n, k, T::arbitrary),
+                    Matrix::arbitrary_with(g, k, m, T::arbitrary))
+    }
+
+    // Drops a row of a, a column of b, or the shared inner dimension
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let Conformable(a, b) = self.clone();
+        let rows = (0..a.rows).rev().map({
+            let (a, b) = (a.clone(), b.clone());
+            move |i| Conformable(a.without_row(i), b.clone())
+        });
+        let cols = (0..b.cols).rev().map({
+            let (a, b) = (a.clone(), b.clone());
+            move |j| Conformable(a.clone(), b.without_col(j))
+        });
+        let inner = (0..a.cols).rev().map(move |l| Conformable(a.without_col(l), b.without_row(l)));
+
+        Box::new(rows.chain(cols).chain(inner))
+    }
+}
+
+impl<T : Arbitrary> Arbitrary for Square<T> {
+    fn arbitrary(g : &mut Gen) -> Self {
+        let n = dimension(g);
+        Square(Matrix::arbitrary_with(g, n, n, T::arbitrary))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let a = self.0.clone();
+        Box::new((0..a.rows).rev().map(move |i| Square(a.without_row(i).without_col(i))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::{Arbitrary, Gen, QuickCheck};
+
+    use super::{Conformable, SameShape, Square};
+    use crate::Matrix;
+
+    #[test]
+    fn generated_shapes() {
+        let mut g = Gen::new(16);
+        for _ in 0..50 {
+            let Conformable(a, b) = Conformable::<i8>::arbitrary(&mut g);
+            assert!(a.cols() == b.rows() && a.rows() <= 4 && b.cols() <= 4);
+
+            let Square(s) = Square::<i8>::arbitrary(&mut g);
+            assert_eq!(s.rows(), s.cols());
+
+            let small = Matrix::arbitrary_with(&mut g, 2, 3, |g| u8::arbitrary(g) % 10);
+            assert!(small.rows() == 2 && small.cols() == 3);
+            assert!(small.into_row_major_vec().iter().all(|&x| x < 10));
+        }
+
+        let a = Matrix::from([[1i8,2],[3,4]]);
+        assert!(a.shrink().take(4).all(|m| m.rows() * m.cols() == 2));
+    }
+
+    #[test]
+    fn algebraic_identities() {
+        fn product_transpose(pair : Conformable<i8>) -> bool {
+            let (a, b) = (pair.0.cast::<i64>(), pair.1.cast::<i64>());
+            let (mut at, mut bt, mut ab) = (a.clone(), b.clone(), &a * &b);
+            at.transpose();
+            bt.transpose();
+            ab.transpose();
+            ab == &bt * &at
+        }
+
+        fn sum_commutes(pair : SameShape<i16>) -> bool {
+            let (a, b) = (pair.0.cast::<i32>(), pair.1.cast::<i32>());
+            &a + &b == &b + &a
+        }
+
+        QuickCheck::new().quickcheck(product_transpose as fn(Conformable<i8>) -> bool);
+        QuickCheck::new().quickcheck(sum_commutes as fn(SameShape<i16>) -> bool);
+    }
+}
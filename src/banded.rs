@@ -0,0 +1,227 @@
+use std::ops::{AddAssign, Div, Index, IndexMut, Mul, Sub};
+
+use num_traits::One;
+
+use crate::Matrix;
+
+// A square matrix which is zero outside a band of kl sub-diagonals and ku
+// super-diagonals. Row i stores the entries for columns i-kl ..= i+ku, with
+// the positions that fall outside the matrix left as padding.
+#[derive(Clone,Debug,PartialEq,Eq)]
+pub struct BandedMatrix<T> {
+    m : Vec<Vec<T>>,
+    n : usize,
+    kl : usize,
+    ku : usize,
+}
+
+impl<T> BandedMatrix<T> {
+    pub fn size(&self) -> usize {
+        self.n
+    }
+
+    pub fn lower_bandwidth(&self) -> usize {
+        self.kl
+    }
+
+    pub fn upper_bandwidth(&self) -> usize {
+        self.ku
+    }
+
+    pub fn in_band(&self, i : usize, j : usize) -> bool {
+        i < self.n && j < self.n && j + self.kl >= i && j <= i + self.ku
+    }
+
+    // Range of columns within the band on row i
+    pub(crate) fn band_cols(&self, i : usize) -> std::ops::Range<usize> {
+        i.saturating_sub(self.kl)..(i + self.ku + 1).min(self.n)
+    }
+}
+
+impl<T : Clone+Default> BandedMatrix<T> {
+    pub fn new(n : usize, kl : usize, ku : usize) -> Self {
+        BandedMatrix {
+            m : vec![vec![Default::default() ; kl + ku + 1] ; n],
+            n,
+            kl,
+            ku,
+        }
+    }
+
+    // Copies the band out of a dense square matrix, dropping everything else
+    pub fn from_dense(other : &Matrix<T>, kl : usize, ku : usize) -> Self {
+        assert!(other.is_square());
+
+        let mut banded = BandedMatrix::new(other.rows, kl, ku);
+        for i in 0..banded.n {
+            for j in banded.band_cols(i) {
+                banded[(i, j)] = other.m[i][j].clone();
+            }
+        }
+        banded
+    }
+
+    pub fn to_dense(&self) -> Matrix<T> {
+        let mut matrix = Matrix::new(self.n, self.n);
+        for i in 0..self.n {
+            for j in self.band_cols(i) {
+                matrix.m[i][j] = self[(i, j)].clone();
+            }
+        }
+        matrix
+    }
+}
+
+impl<T : AddAssign+Clone+Default+Mul<Output=T>> BandedMatrix<T> {
+    // Matrix-vector product Ax, only touching the stored band
+    pub fn mul_vec(&self, x : &[T]) -> Vec<T> {
+        assert!(x.len() == self.n);
+
+        (0..self.n).map(|i| {
+            let mut total = T::default();
+            for j in self.band_cols(i) {
+                total += self[(i, j)].clone() * x[j].clone();
+            }
+            total
+        }).collect()
+    }
+}
+
+impl<T : Clone+Default+One+PartialEq+Sub<Output=T>+Mul<Output=T>+Div<Output=T>> BandedMatrix<T> {
+    // LU factorisation without pivoting, which keeps L within kl
+    // sub-diagonals (with a unit diagonal) and U within ku super-diagonals.
+    // Returns None if a zero pivot turns up.
+    pub fn lu(&self) -> Option<(BandedMatrix<T>, BandedMatrix<T>)> {
+        let zero = T::default();
+        let mut a = self.clone();
+
+        for k in 0..self.n {
+            if a[(k, k)] == zero {
+                return None;
+            }
+            let last_row = (k + self.kl).min(self.n - 1);
+            let last_col = (k + self.ku).min(self.n - 1);
+
+            for i in (k + 1)..=last_row {
+                let factor = a[(i, k)].clone() / a[(k, k)].clone();
+                for j in (k + 1)..=last_col {
+                    a[(i, j)] = a[(i, j)].clone() - factor.clone() * a[(k, j)].clone();
+                }
+                a[(i, k)] = factor;
+            }
+        }
+
+        let mut l = BandedMatrix::new(self.n, self.kl, 0);
+        let mut u = BandedMatrix::new(self.n, 0, self.ku);
+        for i in 0..self.n {
+            for j in a.band_cols(i) {
+                if j < i {
+                    l[(i, j)] = a[(i, j)].clone();
+                } else {
+                    u[(i, j)] = a[(i, j)].clone();
+                }
+            }
+            l[(i, i)] = T::one();
+        }
+
+        Some((l, u))
+    }
+
+    // Solves AX = B through the banded LU factorisation
+    pub fn solve(&self, b : &Matrix<T>) -> Option<Matrix<T>> {
+        assert!(b.rows == self.n);
+
+        let (l, u) = self.lu()?;
+        let mut x = b.m.clone();
+
+        // forward substitution with the unit lower triangular factor
+        for i in 0..self.n {
+            for k in l.band_cols(i).filter(|&k| k < i) {
+                for j in 0..b.cols {
+                    x[i][j] = x[i][j].clone() - l[(i, k)].clone() * x[k][j].clone();
+                }
+            }
+        }
+
+        // and backward substitution with the upper factor
+        for i in (0..self.n).rev() {
+            for k in u.band_cols(i).filter(|&k| k > i) {
+                for j in 0..b.cols {
+                    x[i][j] = x[i][j].clone() - u[(i, k)].clone() * x[k][j].clone();
+                }
+            }
+            for j in 0..b.cols {
+                x[i][j] = x[i][j].clone() / u[(i, i)].clone();
+            }
+        }
+
+        Some(Matrix { m : x, rows : b.rows, cols : b.cols })
+    }
+}
+
+// Indexing panics for positions outside the band
+impl<T> Index<(usize,usize)> for BandedMatrix<T> {
+    type Output = T;
+
+    fn index(&self, ix : (usize, usize)) -> &T {
+        assert!(self.in_band(ix.0, ix.1));
+        &self.m[ix.0][ix.1 + self.kl - ix.0]
+    }
+}
+
+impl<T> IndexMut<(usize,usize)> for BandedMatrix<T> {
+    fn index_mut(&mut self, ix : (usize, usize)) -> &mut T {
+        assert!(self.in_band(ix.0, ix.1));
+        &mut self.m[ix.0][ix.1 + self.kl - ix.0]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BandedMatrix;
+    use crate::{assert_close, Matrix};
+
+    fn pentadiagonal() -> Matrix<f64> {
+        Matrix::from([[6.0,-1.0,0.0,0.0,0.0],
+                      [2.0,6.0,-1.0,0.0,0.0],
+                      [1.0,2.0,6.0,-1.0,0.0],
+                      [0.0,1.0,2.0,6.0,-1.0],
+                      [0.0,0.0,1.0,2.0,6.0]])
+    }
+
+    #[test]
+    fn banded_dense_round_trip() {
+        let a = pentadiagonal();
+        let b = BandedMatrix::from_dense(&a, 2, 1);
+
+        assert_eq!(b.to_dense(), a);
+        assert_eq!(b[(3, 1)], 1.0);
+        assert!(!b.in_band(0, 2));
+    }
+
+    #[test]
+    fn banded_mul_vec() {
+        let a = pentadiagonal();
+        let b = BandedMatrix::from_dense(&a, 2, 1);
+        let x = vec![1.0,2.0,3.0,4.0,5.0];
+        let column = Matrix::from(x.iter().map(|&v| vec![v]).collect::<Vec<_>>());
+
+        let expected : Vec<f64> = (&a * &column).into_row_major_vec();
+        assert_eq!(b.mul_vec(&x), expected);
+    }
+
+    #[test]
+    fn banded_lu_solve() {
+        let a = pentadiagonal();
+        let banded = BandedMatrix::from_dense(&a, 2, 1);
+
+        let (l, u) = banded.lu().unwrap();
+        assert_eq!((l.lower_bandwidth(), l.upper_bandwidth()), (2, 0));
+        assert_eq!((u.lower_bandwidth(), u.upper_bandwidth()), (0, 1));
+        assert_close(&(l.to_dense() * u.to_dense()), &a, 1e-12);
+
+        let b = Matrix::from([[1.0],[0.0],[2.0],[0.0],[1.0]]);
+        let x = banded.solve(&b).unwrap();
+        assert_close(&(&a * &x), &b, 1e-12);
+    }
+}
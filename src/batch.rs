@@ -0,0 +1,72 @@
+use std::ops::{AddAssign, Mul};
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::Matrix;
+
+// Every left operand must have the same shape, likewise every right one
+fn check_batch<T>(lhs : &[Matrix<T>], rhs : &[Matrix<T>]) {
+    assert!(lhs.len() == rhs.len());
+
+    if let (Some(a), Some(b)) = (lhs.first(), rhs.first()) {
+        assert!(a.cols == b.rows);
+        assert!(lhs.iter().all(|m| m.rows == a.rows && m.cols == a.cols));
+        assert!(rhs.iter().all(|m| m.rows == b.rows && m.cols == b.cols));
+    }
+}
+
+// Multiplies lhs[k] · rhs[k] for every k. The shapes are all checked up
+// front, so a bad pair fails before any work is done.
+pub fn matmul_batch<T>(lhs : &[Matrix<T>], rhs : &[Matrix<T>]) -> Vec<Matrix<T>>
+    where T : AddAssign+Clone+Default+Mul<Output=T> {
+    check_batch(lhs, rhs);
+    lhs.iter().zip(rhs.iter()).map(|(a, b)| a * b).collect()
+}
+
+// matmul_batch, spreading the pairs over the rayon thread pool
+#[cfg(feature = "rayon")]
+pub fn matmul_batch_par<T>(lhs : &[Matrix<T>], rhs : &[Matrix<T>]) -> Vec<Matrix<T>>
+    where T : AddAssign+Clone+Default+Mul<Output=T>+Send+Sync {
+    check_batch(lhs, rhs);
+    lhs.par_iter().zip(rhs.par_iter()).map(|(a, b)| a * b).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::matmul_batch;
+    use crate::Matrix;
+
+    fn batch() -> (Vec<Matrix<i64>>, Vec<Matrix<i64>>) {
+        let lhs = (0..50).map(|k| Matrix::from([[k,1,0],[2,k,-1]])).collect();
+        let rhs = (0..50).map(|k| Matrix::from([[1,k],[0,1],[k,2]])).collect();
+        (lhs, rhs)
+    }
+
+    #[test]
+    fn batched_products() {
+        let (lhs, rhs) = batch();
+        let products = matmul_batch(&lhs, &rhs);
+
+        assert_eq!(products.len(), 50);
+        for ((a, b), p) in lhs.iter().zip(rhs.iter()).zip(products.iter()) {
+            assert_eq!(&(a * b), p);
+        }
+        assert!(matmul_batch::<i64>(&[], &[]).is_empty());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn parallel_batched_products() {
+        let (lhs, rhs) = batch();
+        assert_eq!(super::matmul_batch_par(&lhs, &rhs), matmul_batch(&lhs, &rhs));
+    }
+
+    #[test]
+    #[should_panic]
+    fn mismatched_batch_shapes() {
+        let lhs = vec![Matrix::from([[1,2]]), Matrix::from([[1],[2]])];
+        let rhs = vec![Matrix::from([[1],[2]]), Matrix::from([[1],[2]])];
+        matmul_batch(&lhs, &rhs);
+    }
+}
@@ -0,0 +1,246 @@
+use std::ops::{Add, AddAssign, Mul};
+
+use crate::Matrix;
+
+// A matrix over GF(2), with each row packed into u64 words. Bits past the
+// last column are always kept clear so whole-word operations stay valid.
+#[derive(Clone,Debug,PartialEq,Eq,Hash)]
+pub struct BitMatrix {
+    words : Vec<u64>,
+    rows : usize,
+    cols : usize,
+    stride : usize,
+}
+
+impl BitMatrix {
+    pub fn new(rows : usize, cols : usize) -> Self {
+        let stride = cols.div_ceil(64);
+        BitMatrix { words : vec![0 ; rows * stride], rows, cols, stride }
+    }
+
+    pub fn identity(n : usize) -> Self {
+        let mut matrix = BitMatrix::new(n, n);
+        for i in 0..n {
+            matrix.set(i, i, true);
+        }
+        matrix
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn get(&self, i : usize, j : usize) -> bool {
+        assert!(i < self.rows && j < self.cols);
+        self.words[i * self.stride + j / 64] >> (j % 64) & 1 == 1
+    }
+
+    pub fn set(&mut self, i : usize, j : usize, value : bool) {
+        assert!(i < self.rows && j < self.cols);
+        let word = &mut self.words[i * self.stride + j / 64];
+        if value {
+            *word |= 1 << (j % 64);
+        } else {
+            *word &= !(1 << (j % 64));
+        }
+    }
+
+    fn row(&self, i : usize) -> &[u64] {
+        &self.words[i * self.stride..(i + 1) * self.stride]
+    }
+
+    // row[target] ^= row[source]
+    fn xor_row_into(&mut self, source : usize, target : usize) {
+        for w in 0..self.stride {
+            let bits = self.words[source * self.stride + w];
+            self.words[target * self.stride + w] ^= bits;
+        }
+    }
+
+    fn swap_rows(&mut self, a : usize, b : usize) {
+        for w in 0..self.stride {
+            self.words.swap(a * self.stride + w, b * self.stride + w);
+        }
+    }
+
+    pub fn transpose(&self) -> BitMatrix {
+        let mut matrix = BitMatrix::new(self.cols, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                if self.get(i, j) {
+                    matrix.set(j, i, true);
+                }
+            }
+        }
+        matrix
+    }
+
+    // Reduces to reduced row echelon form in place, returning the rank
+    pub fn gaussian_elimination(&mut self) -> usize {
+        let mut rank = 0;
+
+        for col in 0..self.cols {
+            if rank == self.rows {
+                break;
+            }
+            let pivot = match (rank..self.rows).find(|&i| self.get(i, col)) {
+                Some(pivot) => pivot,
+                None => continue,
+            };
+            self.swap_rows(rank, pivot);
+
+            // clearing the column above the pivot too gives the reduced form
+            for i in 0..self.rows {
+                if i != rank && self.get(i, col) {
+                    self.xor_row_into(rank, i);
+                }
+            }
+            rank += 1;
+        }
+
+        rank
+    }
+
+    pub fn rank(&self) -> usize {
+        self.clone().gaussian_elimination()
+    }
+}
+
+impl AddAssign<&BitMatrix> for BitMatrix {
+    fn add_assign(&mut self, other : &BitMatrix) {
+        // can only add matrices of the same size
+        assert!(self.rows == other.rows);
+        assert!(self.cols == other.cols);
+
+        for (a, b) in self.words.iter_mut().zip(other.words.iter()) {
+            *a ^= b;
+        }
+    }
+}
+
+impl Add<&BitMatrix> for &BitMatrix {
+    type Output = BitMatrix;
+
+    fn add(self, other : &BitMatrix) -> BitMatrix {
+        let mut matrix = self.clone();
+        matrix += other;
+        matrix
+    }
+}
+
+impl Add for BitMatrix {
+    type Output = BitMatrix;
+
+    fn add(mut self, other : BitMatrix) -> BitMatrix {
+        self += &other;
+        self
+    }
+}
+
+impl Mul<&BitMatrix> for &BitMatrix {
+    type Output = BitMatrix;
+
+    fn mul(self, rhs : &BitMatrix) -> BitMatrix {
+        // can only multiply if LHS.cols == RHS.rows
+        assert!(self.cols == rhs.rows);
+
+        // with the columns of rhs packed as rows, each entry is the parity of
+        // the popcount of a bitwise AND
+        let t = rhs.transpose();
+        let mut matrix = BitMatrix::new(self.rows, rhs.cols);
+        for i in 0..self.rows {
+            for j in 0..rhs.cols {
+                let ones : u32 = self.row(i).iter().zip(t.row(j).iter()).map(|(a, b)| (a & b).count_ones()).sum();
+                if ones % 2 == 1 {
+                    matrix.set(i, j, true);
+                }
+            }
+        }
+        matrix
+    }
+}
+
+impl Mul for BitMatrix {
+    type Output = BitMatrix;
+
+    fn mul(self, rhs : BitMatrix) -> BitMatrix {
+        (&self) * (&rhs)
+    }
+}
+
+impl From<&Matrix<bool>> for BitMatrix {
+    fn from(other : &Matrix<bool>) -> Self {
+        let mut matrix = BitMatrix::new(other.rows, other.cols);
+        for i in 0..other.rows {
+            for j in 0..other.cols {
+                matrix.set(i, j, other.m[i][j]);
+            }
+        }
+        matrix
+    }
+}
+
+impl From<&BitMatrix> for Matrix<bool> {
+    fn from(other : &BitMatrix) -> Self {
+        Matrix::from_fn(other.rows, other.cols, |i, j| other.get(i, j))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BitMatrix;
+    use crate::Matrix;
+
+    fn bits(rows : &[&str]) -> BitMatrix {
+        let m : Vec<Vec<bool>> = rows.iter().map(|r| r.chars().map(|c| c == '1').collect()).collect();
+        BitMatrix::from(&Matrix::from(m))
+    }
+
+    #[test]
+    fn bit_matrix_add_and_mul() {
+        let a = bits(&["110", "011"]);
+        let b = bits(&["101", "111"]);
+        assert_eq!(&a + &b, bits(&["011", "100"]));
+        assert_eq!(&a + &a, BitMatrix::new(2, 3));
+
+        let c = bits(&["10", "11", "01"]);
+        assert_eq!(&a * &c, bits(&["01", "10"]));
+        assert_eq!(&a * &BitMatrix::identity(3), a);
+    }
+
+    #[test]
+    fn bit_matrix_wide_multiply() {
+        // cross a word boundary so the popcount covers several words
+        let n = 130;
+        let mut a = BitMatrix::new(n, n);
+        for i in 0..n {
+            a.set(i, (i * 7 + 3) % n, true);
+            a.set(i, (i * 11 + 1) % n, true);
+        }
+        let dense : Matrix<bool> = (&a).into();
+
+        let product = &a * &a;
+        for i in 0..n {
+            for j in 0..n {
+                let expected = (0..n).filter(|&k| dense[(i, k)] && dense[(k, j)]).count() % 2 == 1;
+                assert_eq!(product.get(i, j), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn bit_matrix_rank() {
+        // the third row is the sum of the first two
+        let a = bits(&["1011", "0110", "1101", "0001"]);
+        assert_eq!(a.rank(), 3);
+
+        let mut r = a.clone();
+        r.gaussian_elimination();
+        assert_eq!(r, bits(&["1010", "0110", "0001", "0000"]));
+        assert_eq!(BitMatrix::identity(70).rank(), 70);
+    }
+}
@@ -0,0 +1,141 @@
+use std::ops::{Add, AddAssign, Mul, Sub, SubAssign};
+
+use crate::Matrix;
+
+impl<T : Clone> Matrix<T> {
+    // Combines entry (i, j) with v[j], i.e. with a row vector repeated down
+    // every row
+    fn zip_row<F : Fn(T, T) -> T>(&self, v : &[T], f : F) -> Matrix<T> {
+        assert!(v.len() == self.cols);
+        Matrix::from_fn(self.rows, self.cols, |i, j| f(self.m[i][j].clone(), v[j].clone()))
+    }
+
+    // Combines entry (i, j) with v[i], i.e. with a column vector repeated
+    // across every column
+    fn zip_col<F : Fn(T, T) -> T>(&self, v : &[T], f : F) -> Matrix<T> {
+        assert!(v.len() == self.rows);
+        Matrix::from_fn(self.rows, self.cols, |i, j| f(self.m[i][j].clone(), v[i].clone()))
+    }
+}
+
+// Element-wise operations against a vector broadcast along the other axis,
+// e.g. a.sub_row_vector(&means) centres every column
+impl<T : Clone+Add<Output=T>> Matrix<T> {
+    pub fn add_row_vector(&self, v : &[T]) -> Matrix<T> {
+        self.zip_row(v, |a, b| a + b)
+    }
+
+    pub fn add_col_vector(&self, v : &[T]) -> Matrix<T> {
+        self.zip_col(v, |a, b| a + b)
+    }
+}
+
+impl<T : Clone+Sub<Output=T>> Matrix<T> {
+    pub fn sub_row_vector(&self, v : &[T]) -> Matrix<T> {
+        self.zip_row(v, |a, b| a - b)
+    }
+
+    pub fn sub_col_vector(&self, v : &[T]) -> Matrix<T> {
+        self.zip_col(v, |a, b| a - b)
+    }
+}
+
+impl<T : Clone+Mul<Output=T>> Matrix<T> {
+    // Scales column j by v[j], the same as A · diag(v)
+    pub fn mul_row_vector(&self, v : &[T]) -> Matrix<T> {
+        self.zip_row(v, |a, b| a * b)
+    }
+
+    // Scales row i by v[i], the same as diag(v) · A
+    pub fn mul_col_vector(&self, v : &[T]) -> Matrix<T> {
+        self.zip_col(v, |a, b| a * b)
+    }
+}
+
+// Scalar broadcasting: m + s adds s to every entry
+impl<T : AddAssign+Clone> Matrix<T> {
+    pub fn add_scalar(&self, s : T) -> Matrix<T> {
+        self.clone() + s
+    }
+}
+
+impl<T : SubAssign+Clone> Matrix<T> {
+    pub fn sub_scalar(&self, s : T) -> Matrix<T> {
+        self.clone() - s
+    }
+}
+
+impl<T : AddAssign+Clone> Add<T> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn add(mut self, s : T) -> Matrix<T> {
+        for x in self.m.iter_mut().flatten() {
+            *x += s.clone();
+        }
+        self
+    }
+}
+
+impl<T : AddAssign+Clone> Add<T> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn add(self, s : T) -> Matrix<T> {
+        self.clone() + s
+    }
+}
+
+impl<T : SubAssign+Clone> Sub<T> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn sub(mut self, s : T) -> Matrix<T> {
+        for x in self.m.iter_mut().flatten() {
+            *x -= s.clone();
+        }
+        self
+    }
+}
+
+impl<T : SubAssign+Clone> Sub<T> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn sub(self, s : T) -> Matrix<T> {
+        self.clone() - s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Matrix;
+
+    #[test]
+    fn vector_broadcasting() {
+        let a = Matrix::from([[1,2,3],[4,5,6]]);
+
+        assert_eq!(a.add_row_vector(&[10,20,30]), Matrix::from([[11,22,33],[14,25,36]]));
+        assert_eq!(a.sub_row_vector(&[1,2,3]), Matrix::from([[0,0,0],[3,3,3]]));
+        assert_eq!(a.mul_row_vector(&[1,0,-1]), Matrix::from([[1,0,-3],[4,0,-6]]));
+
+        assert_eq!(a.add_col_vector(&[1,-1]), Matrix::from([[2,3,4],[3,4,5]]));
+        assert_eq!(a.sub_col_vector(&[1,4]), Matrix::from([[0,1,2],[0,1,2]]));
+        assert_eq!(a.mul_col_vector(&[2,0]), Matrix::from([[2,4,6],[0,0,0]]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn broadcast_length_mismatch() {
+        Matrix::from([[1,2,3]]).add_row_vector(&[1,2]);
+    }
+
+    #[test]
+    fn scalar_broadcasting() {
+        let a = Matrix::from([[1,2],[3,4]]);
+
+        assert_eq!(&a + 1, Matrix::from([[2,3],[4,5]]));
+        assert_eq!(a.clone() - 1, Matrix::from([[0,1],[2,3]]));
+        assert_eq!(a.add_scalar(10), Matrix::from([[11,12],[13,14]]));
+        assert_eq!(a.sub_scalar(2), &a - 2);
+
+        // matrix + matrix still resolves to the element-wise sum
+        assert_eq!(&a + &a, Matrix::from([[2,4],[6,8]]));
+    }
+}
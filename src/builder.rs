@@ -0,0 +1,106 @@
+use crate::{Matrix, MatrixError};
+
+// Assembles a matrix a row or a column at a time, checking each one against
+// the shape so far. Rows are moved straight into place, so nothing is
+// copied when the matrix is finished.
+#[derive(Clone,Debug,Default)]
+pub struct MatrixBuilder<T> {
+    m : Vec<Vec<T>>,
+    rows : usize,
+    cols : usize,
+}
+
+impl<T> MatrixBuilder<T> {
+    pub fn new() -> Self {
+        MatrixBuilder { m : Vec::new(), rows : 0, cols : 0 }
+    }
+
+    // Reserves room for this many rows
+    pub fn with_capacity(rows : usize) -> Self {
+        MatrixBuilder { m : Vec::with_capacity(rows), rows : 0, cols : 0 }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    fn is_empty(&self) -> bool {
+        self.rows == 0 && self.cols == 0
+    }
+
+    // Appends a row. The first row or column pushed fixes the width.
+    pub fn push_row<R : IntoIterator<Item=T>>(&mut self, row : R) -> Result<&mut Self, MatrixError> {
+        let row : Vec<T> = row.into_iter().collect();
+        if !self.is_empty() && row.len() != self.cols {
+            return Err(MatrixError::RaggedRows { row : self.rows, expected : self.cols, found : row.len() });
+        }
+
+        self.cols = row.len();
+        self.m.push(row);
+        self.rows += 1;
+        Ok(self)
+    }
+
+    // Appends a column, one entry onto the end of each row. The first row
+    // or column pushed fixes the height.
+    pub fn push_col<C : IntoIterator<Item=T>>(&mut self, col : C) -> Result<&mut Self, MatrixError> {
+        let col : Vec<T> = col.into_iter().collect();
+        if self.is_empty() {
+            self.rows = col.len();
+            self.m = (0..self.rows).map(|_| Vec::new()).collect();
+        } else if col.len() != self.rows {
+            return Err(MatrixError::RaggedColumns { col : self.cols, expected : self.rows, found : col.len() });
+        }
+
+        for (row, x) in self.m.iter_mut().zip(col) {
+            row.push(x);
+        }
+        self.cols += 1;
+        Ok(self)
+    }
+
+    pub fn build(self) -> Matrix<T> {
+        Matrix { m : self.m, rows : self.rows, cols : self.cols }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MatrixBuilder;
+    use crate::{Matrix, MatrixError};
+
+    #[test]
+    fn building_by_rows_and_columns() {
+        let mut b = MatrixBuilder::with_capacity(2);
+        for line in "1,2\n3,4".lines() {
+            b.push_row(line.split(',').map(|x| x.parse::<i32>().unwrap())).unwrap();
+        }
+        b.push_col(vec![9, 8]).unwrap().push_row(vec![5, 6, 7]).unwrap();
+        assert_eq!((b.rows(), b.cols()), (3, 3));
+        assert_eq!(b.build(), Matrix::from([[1,2,9],[3,4,8],[5,6,7]]));
+
+        let mut c = MatrixBuilder::new();
+        c.push_col(vec![1, 2]).unwrap().push_col(vec![3, 4]).unwrap();
+        assert_eq!(c.build(), Matrix::from([[1,3],[2,4]]));
+
+        assert_eq!(MatrixBuilder::<i32>::new().build(), Matrix::from(Vec::<Vec<i32>>::new()));
+    }
+
+    #[test]
+    fn builder_shape_errors() {
+        let mut b = MatrixBuilder::new();
+        b.push_row(vec![1, 2, 3]).unwrap();
+
+        assert_eq!(b.push_row(vec![4, 5]).unwrap_err(),
+                   MatrixError::RaggedRows { row : 1, expected : 3, found : 2 });
+        assert_eq!(b.push_col(vec![4, 5]).unwrap_err(),
+                   MatrixError::RaggedColumns { col : 3, expected : 1, found : 2 });
+
+        // a rejected push leaves the builder as it was
+        assert_eq!(b.build(), Matrix::from([[1,2,3]]));
+    }
+}
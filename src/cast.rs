@@ -0,0 +1,52 @@
+use std::convert::TryFrom;
+
+use num_traits::AsPrimitive;
+
+use crate::Matrix;
+
+// Conversions between entry types, each with its own overflow policy
+impl<T : Clone> Matrix<T> {
+    // Lossless conversion through From, e.g. i32 → f64 or f32 → f64
+    pub fn cast<U : From<T>>(&self) -> Matrix<U> {
+        Matrix::from_fn(self.rows, self.cols, |i, j| U::from(self.m[i][j].clone()))
+    }
+
+    // Checked conversion through TryFrom, e.g. i64 → u8, failing on the
+    // first entry (in row-major order) that doesn't fit
+    pub fn try_cast<U : TryFrom<T>>(&self) -> Result<Matrix<U>, U::Error> {
+        let m = self.m.iter()
+            .map(|row| row.iter().map(|x| U::try_from(x.clone())).collect::<Result<Vec<U>, _>>())
+            .collect::<Result<Vec<Vec<U>>, _>>()?;
+
+        Ok(Matrix { m, rows : self.rows, cols : self.cols })
+    }
+}
+
+impl<T : Copy> Matrix<T> {
+    // Conversion with the semantics of `as`: float → float rounds to
+    // nearest, float → integer truncates towards zero and saturates (NaN
+    // becomes 0), integer → integer wraps
+    pub fn cast_as<U : Copy+'static>(&self) -> Matrix<U> where T : AsPrimitive<U> {
+        Matrix::from_fn(self.rows, self.cols, |i, j| self.m[i][j].as_())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Matrix;
+
+    #[test]
+    fn entry_casts() {
+        let a = Matrix::from([[1i32,-2],[300,4]]);
+
+        assert_eq!(a.cast::<f64>(), Matrix::from([[1.0,-2.0],[300.0,4.0]]));
+        assert_eq!(a.try_cast::<i16>(), Ok(Matrix::from([[1i16,-2],[300,4]])));
+        assert!(a.try_cast::<u8>().is_err());
+
+        assert_eq!(a.cast_as::<u8>(), Matrix::from([[1u8,254],[44,4]]));
+
+        let f = Matrix::from([[1.9f64,-1.9],[f64::NAN,1e10]]);
+        assert_eq!(f.cast_as::<i32>(), Matrix::from([[1,-1],[0,i32::MAX]]));
+        assert_eq!(Matrix::from([[0.1f64]]).cast_as::<f32>(), Matrix::from([[0.1f32]]));
+    }
+}
@@ -0,0 +1,210 @@
+use std::ops::Neg;
+
+use num_complex::Complex;
+use num_traits::{Float, Num};
+
+use crate::Matrix;
+
+// Eigendecomposition A = V Λ Vᴴ of a Hermitian matrix. The eigenvalues are
+// real and in decreasing order; the columns of V are orthonormal eigenvectors.
+#[derive(Clone,Debug,PartialEq)]
+pub struct HermitianEigen<T> {
+    pub eigenvalues : Vec<T>,
+    pub eigenvectors : Matrix<Complex<T>>,
+}
+
+impl<T : Clone+Num+Neg<Output=T>> Matrix<Complex<T>> {
+    // Entrywise complex conjugate
+    pub fn conjugate(&self) -> Matrix<Complex<T>> {
+        Matrix::from_fn(self.rows, self.cols, |i, j| self.m[i][j].conj())
+    }
+
+    // Conjugate transpose Aᴴ
+    pub fn adjoint(&self) -> Matrix<Complex<T>> {
+        Matrix::from_fn(self.cols, self.rows, |i, j| self.m[j][i].conj())
+    }
+
+    // A·Bᴴ without forming Bᴴ, the complex counterpart of `mul_transpose`
+    pub fn mul_adjoint(&self, other : &Matrix<Complex<T>>) -> Matrix<Complex<T>> {
+        assert!(self.cols == other.cols);
+
+        Matrix::from_fn(self.rows, other.rows, |i, j| {
+            self.m[i].iter().zip(other.m[j].iter())
+                .fold(Complex::new(T::zero(), T::zero()), |acc, (a, b)| acc + a.clone() * b.conj())
+        })
+    }
+}
+
+impl<T : Float> Matrix<Complex<T>> {
+    // Whether the matrix equals its own conjugate transpose, entrywise
+    // within tol
+    pub fn is_hermitian(&self, tol : T) -> bool {
+        self.is_square() && (0..self.rows).all(|i| {
+            (i..self.cols).all(|j| (self.m[i][j] - self.m[j][i].conj()).norm() <= tol)
+        })
+    }
+
+    // Complex version of the cyclic Jacobi method in `symmetric_eigen`. Each
+    // step first applies a diagonal phase so that a_pq becomes real, after
+    // which the usual real rotation clears it.
+    pub fn hermitian_eigen(&self) -> HermitianEigen<T> {
+        assert!(self.is_square());
+
+        let n = self.rows;
+        let mut a = self.m.clone();
+        let mut v = Matrix::from_fn(n, n, |i, j| if i == j { Complex::from(T::one()) } else { Complex::from(T::zero()) }).m;
+        let two = T::one() + T::one();
+
+        for _sweep in 0..100 {
+            let mut rotated = false;
+
+            for p in 0..n {
+                for q in (p + 1)..n {
+                    let r = a[p][q].norm();
+                    if r == T::zero() || r <= T::epsilon() * (a[p][p].re * a[q][q].re).abs().sqrt() {
+                        continue;
+                    }
+                    rotated = true;
+
+                    // D = diag(1, .., conj(phase), ..) at q, then A ← Dᴴ A D
+                    let phase = a[p][q] / r;
+                    for k in 0..n {
+                        a[k][q] = a[k][q] * phase.conj();
+                    }
+                    for k in 0..n {
+                        a[q][k] = a[q][k] * phase;
+                    }
+                    for row in v.iter_mut() {
+                        row[q] = row[q] * phase.conj();
+                    }
+
+                    let theta = (a[q][q].re - a[p][p].re) / (two * r);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + T::one()).sqrt());
+                    let c = (t * t + T::one()).sqrt().recip();
+                    let s = t * c;
+
+                    for k in 0..n {
+                        let (akp, akq) = (a[k][p], a[k][q]);
+                        a[k][p] = akp.scale(c) - akq.scale(s);
+                        a[k][q] = akp.scale(s) + akq.scale(c);
+                    }
+                    for k in 0..n {
+                        let (apk, aqk) = (a[p][k], a[q][k]);
+                        a[p][k] = apk.scale(c) - aqk.scale(s);
+                        a[q][k] = apk.scale(s) + aqk.scale(c);
+                    }
+                    for row in v.iter_mut() {
+                        let (vkp, vkq) = (row[p], row[q]);
+                        row[p] = vkp.scale(c) - vkq.scale(s);
+                        row[q] = vkp.scale(s) + vkq.scale(c);
+                    }
+                }
+            }
+
+            if !rotated {
+                break;
+            }
+        }
+
+        let mut order : Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| a[j][j].re.partial_cmp(&a[i][i].re).unwrap_or(std::cmp::Ordering::Equal));
+
+        let eigenvalues = order.iter().map(|&k| a[k][k].re).collect();
+        let m = v.iter().map(|row| order.iter().map(|&k| row[k]).collect()).collect();
+        HermitianEigen { eigenvalues, eigenvectors : Matrix { m, rows : n, cols : n } }
+    }
+
+    // Cholesky factorisation A = LLᴴ of a Hermitian positive-definite matrix,
+    // returning the lower triangular L (with a real diagonal). Only the lower
+    // triangle of A is read.
+    pub fn cholesky_hermitian(&self) -> Option<Matrix<Complex<T>>> {
+        assert!(self.is_square());
+
+        let n = self.rows;
+        let zero = Complex::from(T::zero());
+        let mut l = vec![vec![zero ; n] ; n];
+
+        for j in 0..n {
+            let mut d = self.m[j][j].re;
+            for k in 0..j {
+                d = d - l[j][k].norm_sqr();
+            }
+            if d.is_nan() || d <= T::zero() {
+                return None;
+            }
+            l[j][j] = Complex::from(d.sqrt());
+
+            for i in (j + 1)..n {
+                let mut s = self.m[i][j];
+                for k in 0..j {
+                    s = s - l[i][k] * l[j][k].conj();
+                }
+                l[i][j] = s / l[j][j];
+            }
+        }
+
+        Some(Matrix { m : l, rows : n, cols : n })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_complex::Complex;
+
+    use crate::Matrix;
+
+    fn c(re : f64, im : f64) -> Complex<f64> {
+        Complex::new(re, im)
+    }
+
+    fn close(a : &Matrix<Complex<f64>>, b : &Matrix<Complex<f64>>) -> bool {
+        (0..a.rows()).all(|i| (0..a.cols()).all(|j| (a[(i, j)] - b[(i, j)]).norm() < 1e-12))
+    }
+
+    #[test]
+    fn complex_matrix_basics() {
+        let a = Matrix::from([[c(1.0,2.0),c(0.0,-1.0),c(3.0,0.0)],[c(2.0,1.0),c(-1.0,0.0),c(0.0,4.0)]]);
+        assert_eq!(a.conjugate()[(0, 0)], c(1.0,-2.0));
+        let mut t = a.conjugate();
+        t.transpose();
+        assert_eq!(a.adjoint(), t);
+        assert_eq!(a.adjoint().adjoint(), a);
+
+        // (AB)ᴴ = Bᴴ Aᴴ
+        let b = Matrix::from([[c(0.0,1.0),c(1.0,0.0)],[c(2.0,-1.0),c(0.0,0.0)],[c(1.0,1.0),c(-2.0,3.0)]]);
+        assert_eq!((&a * &b).adjoint(), &b.adjoint() * &a.adjoint());
+        assert_eq!(a.mul_adjoint(&b.adjoint()), &a * &b);
+        assert_eq!(a.mul_adjoint(&a), &a * &a.adjoint());
+
+        // the generic ring/field algorithms carry over unchanged
+        let s = Matrix::from([[c(1.0,1.0),c(2.0,0.0)],[c(0.0,-1.0),c(1.0,0.0)]]);
+        assert_eq!(s.determinant(), c(1.0,3.0));
+        let inv = s.inverse_exact().unwrap();
+        assert!(close(&(&s * &inv), &Matrix::identity(2)));
+        assert!(!s.is_hermitian(1e-12));
+    }
+
+    #[test]
+    fn hermitian_eigen_and_cholesky() {
+        let pauli_y = Matrix::from([[c(0.0,0.0),c(0.0,-1.0)],[c(0.0,1.0),c(0.0,0.0)]]);
+        assert!(pauli_y.is_hermitian(0.0));
+        let eigen = pauli_y.hermitian_eigen();
+        assert!((eigen.eigenvalues[0] - 1.0).abs() < 1e-12);
+        assert!((eigen.eigenvalues[1] + 1.0).abs() < 1e-12);
+
+        let a = Matrix::from([[c(4.0,0.0),c(1.0,-2.0),c(0.0,1.0)],
+                              [c(1.0,2.0),c(6.0,0.0),c(2.0,-1.0)],
+                              [c(0.0,-1.0),c(2.0,1.0),c(5.0,0.0)]]);
+        let eigen = a.hermitian_eigen();
+        let v = &eigen.eigenvectors;
+        assert!(close(&(&v.adjoint() * v), &Matrix::identity(3)));
+        let lambda = Matrix::from_fn(3, 3, |i, j| if i == j { c(eigen.eigenvalues[i], 0.0) } else { c(0.0, 0.0) });
+        assert!(close(&(&a * v), &(v * &lambda)));
+
+        let l = a.cholesky_hermitian().unwrap();
+        assert!(close(&(&l * &l.adjoint()), &a));
+        assert!(l[(0, 1)] == c(0.0, 0.0) && l[(1, 1)].im == 0.0);
+
+        assert_eq!(pauli_y.cholesky_hermitian(), None);
+    }
+}
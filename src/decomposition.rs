@@ -0,0 +1,125 @@
+use num_traits::Float;
+
+use crate::Matrix;
+
+impl<T : Float> Matrix<T> {
+    // Cholesky factorisation A = LLᵀ of a symmetric positive-definite matrix,
+    // returning the lower triangular L. Only the lower triangle of A is read.
+    // Returns None if A turns out not to be positive-definite.
+    pub fn cholesky(&self) -> Option<Matrix<T>> {
+        assert!(self.is_square());
+
+        let n = self.rows;
+        let mut l = vec![vec![T::zero() ; n] ; n];
+
+        for j in 0..n {
+            let mut d = self.m[j][j];
+            for k in 0..j {
+                d = d - l[j][k] * l[j][k];
+            }
+            if d.is_nan() || d <= T::zero() {
+                return None;
+            }
+            l[j][j] = d.sqrt();
+
+            for i in (j + 1)..n {
+                let mut s = self.m[i][j];
+                for k in 0..j {
+                    s = s - l[i][k] * l[j][k];
+                }
+                l[i][j] = s / l[j][j];
+            }
+        }
+
+        Some(Matrix { m : l, rows : n, cols : n })
+    }
+
+    // A = LDLᵀ for a symmetric matrix, with L unit lower triangular and D
+    // diagonal, returning L and the diagonal of D. Unlike Cholesky this
+    // takes no square roots and works for indefinite matrices, but without
+    // pivoting it fails (None) on a pivot that is zero relative to the
+    // scale of A, as for [[0, 1], [1, 0]]. Only the lower triangle is read.
+    pub fn ldlt(&self) -> Option<(Matrix<T>, Vec<T>)> {
+        assert!(self.is_square());
+
+        let n = self.rows;
+        let scale = self.m.iter().flatten().fold(T::zero(), |acc, x| acc.max(x.abs()));
+        let tiny = scale * T::epsilon() * T::from(n).unwrap();
+
+        let mut l = vec![vec![T::zero() ; n] ; n];
+        let mut d = vec![T::zero() ; n];
+        for j in 0..n {
+            let mut dj = self.m[j][j];
+            for k in 0..j {
+                dj = dj - l[j][k] * l[j][k] * d[k];
+            }
+            if dj.is_nan() || dj.abs() <= tiny {
+                return None;
+            }
+            d[j] = dj;
+            l[j][j] = T::one();
+
+            for i in (j + 1)..n {
+                let mut s = self.m[i][j];
+                for k in 0..j {
+                    s = s - l[i][k] * l[j][k] * d[k];
+                }
+                l[i][j] = s / dj;
+            }
+        }
+
+        Some((Matrix { m : l, rows : n, cols : n }, d))
+    }
+
+    // Symmetric (up to rounding) and positive-definite, checked by attempting
+    // a Cholesky factorisation
+    pub fn is_positive_definite(&self) -> bool {
+        if !self.is_square() {
+            return false;
+        }
+
+        let scale = self.m.iter().flatten().fold(T::zero(), |acc, x| acc.max(x.abs()));
+        self.is_symmetric(T::epsilon().sqrt() * scale) && self.cholesky().is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Matrix;
+
+    #[test]
+    fn basic_cholesky() {
+        let a = Matrix::from([[4.0,12.0,-16.0],[12.0,37.0,-43.0],[-16.0,-43.0,98.0]]);
+        let l = a.cholesky().unwrap();
+
+        assert_eq!(l, Matrix::from([[2.0,0.0,0.0],[6.0,1.0,0.0],[-8.0,5.0,3.0]]));
+    }
+
+    #[test]
+    fn positive_definite_check() {
+        assert!(Matrix::from([[2.0,-1.0],[-1.0,2.0]]).is_positive_definite());
+
+        // symmetric but indefinite
+        assert!(!Matrix::from([[1.0,2.0],[2.0,1.0]]).is_positive_definite());
+        // not symmetric
+        assert!(!Matrix::from([[2.0,0.0],[-3.0,2.0]]).is_positive_definite());
+        assert!(!Matrix::from([[1.0,0.0,0.0]]).is_positive_definite());
+    }
+
+    #[test]
+    fn ldlt_of_indefinite_matrix() {
+        let a = Matrix::from([[4.0f64,2.0,-2.0],[2.0,-3.0,1.0],[-2.0,1.0,5.0]]);
+        assert!(a.cholesky().is_none());
+
+        let (l, d) = a.ldlt().unwrap();
+        assert!(l.is_lower_triangular(0.0));
+        assert_eq!(d.iter().filter(|&&x| x < 0.0).count(), 1);
+
+        let mut lt = l.clone();
+        lt.transpose();
+        let product = &l.mul_row_vector(&d) * &lt;
+        assert!((&product - &a).entries_vanish(&1e-12, |_, _| true));
+
+        assert!(Matrix::from([[0.0f64,1.0],[1.0,0.0]]).ldlt().is_none());
+    }
+}
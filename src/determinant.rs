@@ -0,0 +1,208 @@
+use std::ops::{Add, Mul, Sub};
+
+use num_traits::One;
+
+use crate::Matrix;
+
+impl<T : Clone> Matrix<T> {
+    // Copy of the matrix with row i and column j removed
+    fn without(&self, i : usize, j : usize) -> Matrix<T> {
+        let m = self.m.iter().enumerate()
+            .filter(|&(r, _)| r != i)
+            .map(|(_, row)| {
+                row.iter().enumerate().filter(|&(c, _)| c != j).map(|(_, x)| x.clone()).collect()
+            })
+            .collect();
+
+        Matrix { m, rows : self.rows - 1, cols : self.cols - 1 }
+    }
+}
+
+// These only need ring operations, so they work for integer matrices too
+impl<T : Clone+Default+One+Add<Output=T>+Sub<Output=T>+Mul<Output=T>> Matrix<T> {
+    // Determinant using closed forms up to 4×4, and the characteristic
+    // polynomial's constant term (Berkowitz) beyond that, which is O(n⁴)
+    // and division free. Intermediate products grow like ‖A‖ⁿ rather than
+    // with the determinant, so bounded integers can overflow before the
+    // result would; determinant_bareiss keeps them smaller.
+    pub fn determinant(&self) -> T {
+        assert!(self.is_square());
+
+        let a = |i : usize, j : usize| self.m[i][j].clone();
+        match self.rows {
+            0 => T::one(),
+            1 => a(0,0),
+            2 => a(0,0) * a(1,1) - a(0,1) * a(1,0),
+            3 => {
+                a(0,0) * (a(1,1) * a(2,2) - a(1,2) * a(2,1))
+                    - a(0,1) * (a(1,0) * a(2,2) - a(1,2) * a(2,0))
+                    + a(0,2) * (a(1,0) * a(2,1) - a(1,1) * a(2,0))
+            }
+            4 => {
+                let [s0, s1, s2, s3, s4, s5] = self.upper_minors();
+                let [c0, c1, c2, c3, c4, c5] = self.lower_minors();
+                s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0
+            }
+            n => {
+                // det(xI - A) at x = 0 is det(-A)
+                let p0 = self.berkowitz().swap_remove(0);
+                if n.is_multiple_of(2) { p0 } else { T::default() - p0 }
+            }
+        }
+    }
+
+    // Coefficients of det(xI - A) in increasing powers of x, by Berkowitz'
+    // algorithm: bordering the leading k×k block A_k with column c and row r
+    // multiplies its polynomial by a Toeplitz matrix with first column
+    // (1, -a_kk, -rc, -rA_kc, -rA_k²c, ...). Ring operations only.
+    fn berkowitz(&self) -> Vec<T> {
+        let zero = T::default();
+        let dot = |i : usize, v : &[T]| v.iter().enumerate()
+            .fold(zero.clone(), |acc, (j, x)| acc + self.m[i][j].clone() * x.clone());
+
+        // in decreasing powers while it is built up
+        let mut p = vec![T::one()];
+        for k in 0..self.rows {
+            let mut t = vec![T::one(), zero.clone() - self.m[k][k].clone()];
+            let mut v : Vec<T> = (0..k).map(|i| self.m[i][k].clone()).collect();
+            for step in 0..k {
+                t.push(zero.clone() - dot(k, &v));
+                if step + 1 < k {
+                    v = (0..k).map(|i| dot(i, &v)).collect();
+                }
+            }
+
+            p = (0..k + 2)
+                .map(|i| (0..=i.min(k)).fold(zero.clone(), |acc, j| acc + t[i - j].clone() * p[j].clone()))
+                .collect();
+        }
+
+        p.reverse();
+        p
+    }
+
+    // The six 2×2 minors of a 4×4 matrix taken from its top two rows
+    pub(crate) fn upper_minors(&self) -> [T; 6] {
+        self.row_pair_minors(0, 1)
+    }
+
+    // The six 2×2 minors of a 4×4 matrix taken from its bottom two rows
+    pub(crate) fn lower_minors(&self) -> [T; 6] {
+        self.row_pair_minors(2, 3)
+    }
+
+    // 2×2 minors of rows r and s, for column pairs in the order
+    // (0,1), (0,2), (0,3), (1,2), (1,3), (2,3)
+    fn row_pair_minors(&self, r : usize, s : usize) -> [T; 6] {
+        let a = |i : usize, j : usize| self.m[i][j].clone();
+        let minor = |p : usize, q : usize| a(r,p) * a(s,q) - a(s,p) * a(r,q);
+        [minor(0,1), minor(0,2), minor(0,3), minor(1,2), minor(1,3), minor(2,3)]
+    }
+
+    // Determinant of the matrix with row i and column j removed
+    pub fn minor(&self, i : usize, j : usize) -> T {
+        self.without(i, j).determinant()
+    }
+
+    // Signed minor (-1)^(i+j) M_ij
+    pub fn cofactor(&self, i : usize, j : usize) -> T {
+        let minor = self.minor(i, j);
+        if (i + j).is_multiple_of(2) {
+            minor
+        } else {
+            T::default() - minor
+        }
+    }
+
+    pub fn cofactor_matrix(&self) -> Matrix<T> {
+        let mut matrix = self.adjugate();
+        matrix.transpose();
+        matrix
+    }
+
+    // Transpose of the cofactor matrix, so that A adj(A) = det(A) I. Up to
+    // 4×4 each cofactor is a closed form; beyond that Cayley-Hamilton gives
+    // adj(A) = (-1)ⁿ⁺¹ (Aⁿ⁻¹ + p_{n-1}Aⁿ⁻² + ... + p_1 I) for the
+    // characteristic polynomial p, evaluated by Horner's rule in O(n⁴).
+    pub fn adjugate(&self) -> Matrix<T> {
+        assert!(self.is_square());
+
+        let n = self.rows;
+        if n <= 4 {
+            return Matrix::from_fn(n, n, |i, j| self.cofactor(j, i));
+        }
+
+        let p = self.berkowitz();
+        let zero = T::default();
+        let mut q = Matrix::from_fn(n, n, |i, j| if i == j { T::one() } else { zero.clone() });
+        for k in (1..n).rev() {
+            q = Matrix::from_fn(n, n, |i, j| {
+                let aq = (0..n).fold(zero.clone(), |acc, l| acc + self.m[i][l].clone() * q.m[l][j].clone());
+                if i == j { aq + p[k].clone() } else { aq }
+            });
+        }
+
+        if n.is_multiple_of(2) {
+            Matrix::from_fn(n, n, |i, j| zero.clone() - q.m[i][j].clone())
+        } else {
+            q
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Matrix;
+
+    #[test]
+    fn basic_determinant() {
+        assert_eq!(Matrix::from([[3]]).determinant(), 3);
+        assert_eq!(Matrix::from([[1,2],[3,4]]).determinant(), -2);
+        assert_eq!(Matrix::from([[2,0,1],[1,3,2],[1,1,2]]).determinant(), 6);
+    }
+
+    #[test]
+    fn basic_cofactors() {
+        let a = Matrix::from([[1,2,3],[0,4,5],[1,0,6]]);
+
+        assert_eq!(a.cofactor(0, 0), 24);
+        assert_eq!(a.cofactor(0, 1), 5);
+        assert_eq!(a.cofactor_matrix(), Matrix::from([[24,5,-4],[-12,3,2],[-2,-5,4]]));
+        assert_eq!(a.adjugate(), Matrix::from([[24,-12,-2],[5,3,-5],[-4,2,4]]));
+    }
+
+    #[test]
+    fn adjugate_gives_scaled_identity() {
+        let a = Matrix::from([[2,-1,0,3],[1,1,4,0],[0,2,-3,1],[5,0,1,2]]);
+        let det = a.determinant();
+        let mut d = Matrix::new(4, 4);
+        for i in 0..4 {
+            d.m[i][i] = det;
+        }
+
+        assert_eq!(&a * &a.adjugate(), d);
+    }
+
+    #[test]
+    fn large_determinant_and_adjugate() {
+        let a = Matrix::from([[0i64,2,1,0,3,1],[1,0,0,2,1,4],[4,1,3,0,0,2],
+                              [0,1,0,1,2,0],[2,0,1,1,1,3],[1,5,2,0,1,1]]);
+        let expansion = (0..6).fold(0, |acc, j| {
+            let term = a.m[0][j] * a.minor(0, j);
+            if j % 2 == 0 { acc + term } else { acc - term }
+        });
+        assert_eq!(a.determinant(), expansion);
+        assert_eq!(a.determinant(), a.determinant_bareiss());
+
+        let adj = a.adjugate();
+        assert_eq!(adj[(2, 4)], a.cofactor(4, 2));
+        assert_eq!(a.cofactor_matrix()[(3, 1)], a.cofactor(3, 1));
+
+        // 12×12 would take seconds by cofactor expansion
+        let n = 12;
+        let b = Matrix::from_fn(n, n, |i, j| ((7 * i + 3 * j) % 5) as i64 - 2 + if i == j { 3 } else { 0 });
+        let det = b.determinant();
+        assert_eq!(det, b.determinant_bareiss());
+        assert_eq!(&b * &b.adjugate(), Matrix::from_fn(n, n, |i, j| if i == j { det } else { 0 }));
+    }
+}
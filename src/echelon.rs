@@ -0,0 +1,327 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use num_traits::One;
+
+use crate::Matrix;
+
+// An elementary row operation, as applied by rref_with_pivots
+#[derive(Clone,Debug,PartialEq,Eq)]
+pub enum RowOperation<T> {
+    // exchange rows i and j
+    Swap(usize, usize),
+    // multiply a row by a non-zero factor
+    Scale { row : usize, factor : T },
+    // row[target] += factor · row[source]
+    AddMultiple { target : usize, source : usize, factor : T },
+}
+
+// The reduced row echelon form together with the column of each pivot (in
+// order, one per pivot row) and the operations that produced it, in the
+// order they were applied
+#[derive(Clone,Debug,PartialEq,Eq)]
+pub struct Rref<T> {
+    pub reduced : Matrix<T>,
+    pub pivots : Vec<usize>,
+    pub operations : Vec<RowOperation<T>>,
+}
+
+// Elimination over exact fields (rationals, finite fields). Pivots are the
+// first non-zero entry in a column rather than the largest, and the only
+// test ever made on an entry is whether it is exactly zero, so no tolerance
+// is involved anywhere.
+impl<T> Matrix<T>
+    where T : Clone+Default+One+PartialEq+Sub<Output=T>+Mul<Output=T>+Div<Output=T> {
+    // Reduced row echelon form
+    pub fn rref(&self) -> Matrix<T> {
+        let mut r = self.clone();
+        eliminate(&mut r.m, self.cols);
+        r
+    }
+
+    // rref, also recording the pivot columns and row operations. The rank is
+    // pivots.len(), and the columns without a pivot are the free variables.
+    pub fn rref_with_pivots(&self) -> Rref<T> {
+        let mut reduced = self.clone();
+        let mut operations = Vec::new();
+        let pivots = eliminate_recorded(&mut reduced.m, self.cols, |op| operations.push(op));
+
+        Rref { reduced, pivots, operations }
+    }
+
+    // Columns holding a pivot in the reduced row echelon form; those columns
+    // of A are linearly independent and span its column space
+    pub fn pivot_columns(&self) -> Vec<usize> {
+        self.rref_with_pivots().pivots
+    }
+
+    // Basis of the column space (image) made of the pivot columns of A
+    // itself, so exact entries stay as they were
+    pub fn column_space_exact(&self) -> Matrix<T> {
+        let pivots = self.pivot_columns();
+        Matrix::from_fn(self.rows, pivots.len(), |i, k| self.m[i][pivots[k]].clone())
+    }
+
+    // Basis of the null space {x : Ax = 0}, one column per free variable of
+    // the reduced form: each sets its free variable to one, the others to
+    // zero, and solves for the pivot variables. A full column rank matrix
+    // gives a cols × 0 result.
+    pub fn null_space_exact(&self) -> Matrix<T> {
+        let Rref { reduced, pivots, .. } = self.rref_with_pivots();
+        let free : Vec<usize> = (0..self.cols).filter(|j| !pivots.contains(j)).collect();
+
+        let mut basis = Matrix::from_fn(self.cols, free.len(), |i, k| {
+            if i == free[k] { T::one() } else { T::default() }
+        });
+        for (r, &p) in pivots.iter().enumerate() {
+            for (k, &f) in free.iter().enumerate() {
+                basis.m[p][k] = T::default() - reduced.m[r][f].clone();
+            }
+        }
+
+        basis
+    }
+
+    // Determinant by Gaussian elimination, O(n³) rather than the O(n!)
+    // expansion used by `determinant`
+    pub fn determinant_exact(&self) -> T {
+        assert!(self.is_square());
+
+        let mut a = self.m.clone();
+        let zero = T::default();
+        let mut det = T::one();
+
+        for col in 0..self.cols {
+            let pivot = match (col..self.rows).find(|&row| a[row][col] != zero) {
+                Some(pivot) => pivot,
+                None => return zero,
+            };
+            if pivot != col {
+                a.swap(col, pivot);
+                det = zero.clone() - det;
+            }
+            det = det * a[col][col].clone();
+
+            for row in (col + 1)..self.rows {
+                if a[row][col] == zero {
+                    continue;
+                }
+                let factor = a[row][col].clone() / a[col][col].clone();
+                for j in col..self.cols {
+                    a[row][j] = a[row][j].clone() - factor.clone() * a[col][j].clone();
+                }
+            }
+        }
+
+        det
+    }
+
+    // Inverse by Gauss-Jordan elimination of [A | I], or None if A is
+    // singular. Unlike `inverse` this needs no ordering on T.
+    pub fn inverse_exact(&self) -> Option<Matrix<T>> {
+        assert!(self.is_square());
+
+        let n = self.rows;
+        let mut augmented = self.augment(&Matrix::identity(n));
+
+        if eliminate(&mut augmented.m, n) < n {
+            return None;
+        }
+
+        Some(augmented.split_cols_at(n).1)
+    }
+}
+
+impl<T> Rref<T>
+    where T : Clone+Default+One+PartialEq+Add<Output=T>+Sub<Output=T>+Mul<Output=T>+Div<Output=T> {
+    // Replays the row operations on other, which must have as many rows as
+    // the reduced matrix: for a right hand side b, AX = b is solvable
+    // exactly when apply(b) is zero in every row past the last pivot
+    pub fn apply(&self, other : &Matrix<T>) -> Matrix<T> {
+        assert!(other.rows == self.reduced.rows);
+
+        let mut m = other.m.clone();
+        for op in self.operations.iter() {
+            match op {
+                RowOperation::Swap(i, j) => m.swap(*i, *j),
+                RowOperation::Scale { row, factor } => {
+                    for x in m[*row].iter_mut() {
+                        *x = x.clone() * factor.clone();
+                    }
+                }
+                RowOperation::AddMultiple { target, source, factor } => {
+                    for j in 0..other.cols {
+                        let delta = factor.clone() * m[*source][j].clone();
+                        m[*target][j] = m[*target][j].clone() + delta;
+                    }
+                }
+            }
+        }
+
+        Matrix { m, rows : other.rows, cols : other.cols }
+    }
+}
+
+// Gauss-Jordan elimination in place, choosing pivots from the first
+// `pivot_cols` columns only. Each pivot row is scaled to a leading one and
+// its column cleared everywhere else. Returns the rank.
+pub(crate) fn eliminate<T>(rows : &mut [Vec<T>], pivot_cols : usize) -> usize
+    where T : Clone+Default+One+PartialEq+Sub<Output=T>+Mul<Output=T>+Div<Output=T> {
+    eliminate_recorded(rows, pivot_cols, |_| {}).len()
+}
+
+// eliminate, passing each row operation to record as it is made and
+// returning the pivot columns
+fn eliminate_recorded<T, F>(rows : &mut [Vec<T>], pivot_cols : usize, mut record : F) -> Vec<usize>
+    where T : Clone+Default+One+PartialEq+Sub<Output=T>+Mul<Output=T>+Div<Output=T>,
+          F : FnMut(RowOperation<T>) {
+    let zero = T::default();
+    let mut pivots = Vec::new();
+
+    for col in 0..pivot_cols {
+        let rank = pivots.len();
+        let pivot = match (rank..rows.len()).find(|&row| rows[row][col] != zero) {
+            Some(pivot) => pivot,
+            None => continue,
+        };
+        if pivot != rank {
+            rows.swap(rank, pivot);
+            record(RowOperation::Swap(rank, pivot));
+        }
+
+        let p = rows[rank][col].clone();
+        if p != T::one() {
+            for x in rows[rank].iter_mut() {
+                *x = x.clone() / p.clone();
+            }
+            record(RowOperation::Scale { row : rank, factor : T::one() / p });
+        }
+
+        for row in 0..rows.len() {
+            if row == rank || rows[row][col] == zero {
+                continue;
+            }
+            let factor = rows[row][col].clone();
+            for j in 0..rows[row].len() {
+                let delta = factor.clone() * rows[rank][j].clone();
+                rows[row][j] = rows[row][j].clone() - delta;
+            }
+            record(RowOperation::AddMultiple { target : row, source : rank, factor : zero.clone() - factor });
+        }
+
+        pivots.push(col);
+    }
+
+    pivots
+}
+
+#[cfg(test)]
+mod tests {
+    use num_rational::Ratio;
+
+    use crate::Matrix;
+
+    fn q(n : i64, d : i64) -> Ratio<i64> {
+        Ratio::new(n, d)
+    }
+
+    fn rational(m : Vec<Vec<i64>>) -> Matrix<Ratio<i64>> {
+        let m : Vec<Vec<Ratio<i64>>> = m.into_iter()
+            .map(|row| row.into_iter().map(Ratio::from_integer).collect())
+            .collect();
+        Matrix::from(m)
+    }
+
+    #[test]
+    fn rational_rref() {
+        let a = rational(vec![vec![2,4,-2,2],vec![4,9,-3,8],vec![-2,-3,7,10]]);
+        assert_eq!(a.rref(), rational(vec![vec![1,0,0,-1],vec![0,1,0,2],vec![0,0,1,2]]));
+
+        // dependent rows leave a zero row and a free column
+        let b = Matrix::from(vec![vec![q(1,2),q(1,3)],vec![q(3,2),q(1,1)],vec![q(0,1),q(0,1)]]);
+        assert_eq!(b.rref(), Matrix::from(vec![
+            vec![q(1,1),q(2,3)],vec![q(0,1),q(0,1)],vec![q(0,1),q(0,1)],
+        ]));
+    }
+
+    #[test]
+    fn rref_of_augmented_system() {
+        let a = rational(vec![vec![2,4,-2],vec![4,9,-3],vec![-2,-3,7]]);
+        let b = rational(vec![vec![2,1],vec![8,0],vec![10,0]]);
+
+        let augmented = a.augment(&b);
+        assert_eq!((augmented.rows(), augmented.cols()), (3, 5));
+        assert_eq!(augmented.clone().split_cols_at(3), (a.clone(), b.clone()));
+
+        let (reduced, x) = augmented.rref().split_cols_at(3);
+        assert_eq!(reduced, Matrix::identity(3));
+        assert_eq!(x.column(0), vec![q(-1,1),q(2,1),q(2,1)]);
+        assert_eq!(&a * &x, b);
+    }
+
+    #[test]
+    fn rref_pivots_and_operations() {
+        use super::RowOperation;
+
+        let a = rational(vec![vec![0,2,4,2],vec![1,1,1,1],vec![2,4,6,4]]);
+        let r = a.rref_with_pivots();
+
+        assert_eq!(r.reduced, a.rref());
+        assert_eq!(r.reduced, rational(vec![vec![1,0,-1,0],vec![0,1,2,1],vec![0,0,0,0]]));
+        assert_eq!(r.pivots, vec![0,1]);
+        assert_eq!(r.operations[0], RowOperation::Swap(0, 1));
+
+        // replaying the operations on A reproduces the reduced form, and on
+        // a right hand side decides solvability from the zero rows
+        assert_eq!(r.apply(&a), r.reduced);
+        let consistent = r.apply(&rational(vec![vec![2],vec![1],vec![4]]));
+        assert_eq!(consistent[(2, 0)], q(0,1));
+        let inconsistent = r.apply(&rational(vec![vec![2],vec![1],vec![5]]));
+        assert_ne!(inconsistent[(2, 0)], q(0,1));
+    }
+
+    #[test]
+    fn exact_null_space() {
+        let a = rational(vec![vec![1,2,0,3],vec![2,4,1,8],vec![3,6,1,11]]);
+        let n = a.null_space_exact();
+
+        assert_eq!((n.rows(), n.cols()), (4, 2));
+        assert_eq!(&a * &n, Matrix::new(3, 2));
+        assert_eq!(n.column(0), vec![q(-2,1),q(1,1),q(0,1),q(0,1)]);
+
+        let full = rational(vec![vec![1,2],vec![3,4]]).null_space_exact();
+        assert_eq!((full.rows(), full.cols()), (2, 0));
+    }
+
+    #[test]
+    fn exact_column_space() {
+        let a = rational(vec![vec![1,2,0,3],vec![2,4,1,8],vec![3,6,1,11]]);
+
+        assert_eq!(a.pivot_columns(), vec![0,2]);
+        assert_eq!(a.column_space_exact(), rational(vec![vec![1,0],vec![2,1],vec![3,1]]));
+
+        // rank–nullity
+        assert_eq!(a.column_space_exact().cols() + a.null_space_exact().cols(), a.cols());
+    }
+
+    #[test]
+    fn rational_determinant_and_inverse() {
+        let a = rational(vec![vec![0,2,1,0,3],vec![1,0,0,2,1],vec![4,1,3,0,0],
+                              vec![0,1,0,1,2],vec![2,0,1,1,1]]);
+        let det = a.determinant_exact();
+        assert_eq!(det, a.determinant());
+
+        let inv = a.inverse_exact().unwrap();
+        assert_eq!(&a * &inv, Matrix::identity(5));
+
+        // the exact inverse of a Hilbert matrix has integer entries
+        let h = Matrix::from(vec![vec![q(1,1),q(1,2),q(1,3)],
+                                  vec![q(1,2),q(1,3),q(1,4)],
+                                  vec![q(1,3),q(1,4),q(1,5)]]);
+        assert_eq!(h.determinant_exact(), q(1,2160));
+        assert_eq!(h.inverse_exact(), Some(rational(vec![vec![9,-36,30],vec![-36,192,-180],vec![30,-180,180]])));
+        assert_eq!(h.min_poly(), h.char_poly());
+
+        assert_eq!(rational(vec![vec![1,2],vec![2,4]]).inverse_exact(), None);
+        assert_eq!(rational(vec![vec![1,2],vec![2,4]]).determinant_exact(), q(0,1));
+    }
+}
@@ -0,0 +1,92 @@
+use num_traits::Float;
+
+use crate::Matrix;
+
+// Eigendecomposition A = V Λ Vᵀ of a symmetric matrix, with the eigenvalues
+// in decreasing order and the matching unit eigenvectors as the columns of V
+#[derive(Clone,Debug,PartialEq)]
+pub struct SymmetricEigen<T> {
+    pub eigenvalues : Vec<T>,
+    pub eigenvectors : Matrix<T>,
+}
+
+impl<T : Float> Matrix<T> {
+    // Cyclic Jacobi eigenvalue algorithm: repeatedly rotates away the
+    // off-diagonal entries. Only suitable for symmetric matrices, and
+    // slower than tridiagonal QR for big ones, but simple and accurate.
+    pub fn symmetric_eigen(&self) -> SymmetricEigen<T> {
+        assert!(self.is_square());
+
+        let n = self.rows;
+        let mut a = self.m.clone();
+        let mut v = Matrix::from_fn(n, n, |i, j| if i == j { T::one() } else { T::zero() }).m;
+        let two = T::one() + T::one();
+
+        for _sweep in 0..100 {
+            let mut rotated = false;
+
+            for p in 0..n {
+                for q in (p + 1)..n {
+                    let apq = a[p][q];
+                    if apq == T::zero() || apq.abs() <= T::epsilon() * (a[p][p] * a[q][q]).abs().sqrt() {
+                        continue;
+                    }
+                    rotated = true;
+
+                    let theta = (a[q][q] - a[p][p]) / (two * apq);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + T::one()).sqrt());
+                    let c = (t * t + T::one()).sqrt().recip();
+                    let s = t * c;
+
+                    for k in 0..n {
+                        let (akp, akq) = (a[k][p], a[k][q]);
+                        a[k][p] = c * akp - s * akq;
+                        a[k][q] = s * akp + c * akq;
+                    }
+                    for k in 0..n {
+                        let (apk, aqk) = (a[p][k], a[q][k]);
+                        a[p][k] = c * apk - s * aqk;
+                        a[q][k] = s * apk + c * aqk;
+                    }
+                    for row in v.iter_mut() {
+                        let (vkp, vkq) = (row[p], row[q]);
+                        row[p] = c * vkp - s * vkq;
+                        row[q] = s * vkp + c * vkq;
+                    }
+                }
+            }
+
+            if !rotated {
+                break;
+            }
+        }
+
+        let mut order : Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| a[j][j].partial_cmp(&a[i][i]).unwrap_or(std::cmp::Ordering::Equal));
+
+        let eigenvalues = order.iter().map(|&k| a[k][k]).collect();
+        let m = v.iter().map(|row| order.iter().map(|&k| row[k]).collect()).collect();
+        SymmetricEigen { eigenvalues, eigenvectors : Matrix { m, rows : n, cols : n } }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{assert_close, Matrix};
+
+    #[test]
+    fn basic_symmetric_eigen() {
+        let a = Matrix::from([[2.0f64,-1.0,0.0],[-1.0,2.0,-1.0],[0.0,-1.0,2.0]]);
+        let eigen = a.symmetric_eigen();
+
+        let expected = [2.0 + 2f64.sqrt(), 2.0, 2.0 - 2f64.sqrt()];
+        for (l, e) in eigen.eigenvalues.iter().zip(expected.iter()) {
+            assert!((l - e).abs() < 1e-12);
+        }
+        assert!(eigen.eigenvectors.is_orthogonal(1e-12));
+
+        // A v = λ v for every pair
+        let vl = Matrix::from_fn(3, 3, |i, k| eigen.eigenvalues[k] * eigen.eigenvectors[(i, k)]);
+        assert_close(&(&a * &eigen.eigenvectors), &vl, 1e-12);
+    }
+}
@@ -0,0 +1,154 @@
+use std::ops::{AddAssign, Mul};
+
+use crate::{Matrix, MatrixError};
+
+fn invalid<T>(reason : String) -> Result<T, MatrixError> {
+    Err(MatrixError::InvalidSubscripts(reason))
+}
+
+// Einstein summation over matrix operands, e.g.
+//     einsum("ij,jk->ik", &[&a, &b])   product
+//     einsum("ij->ji", &[&a])          transpose
+//     einsum("ii->", &[&a])            trace
+//     einsum("ij,ij->ij", &[&a, &b])   element-wise product
+//     einsum("ij,ij->", &[&a, &b])     Frobenius inner product
+// Each operand takes two subscript letters. Letters missing from the
+// output are summed over. The output has at most two letters: none gives
+// a 1×1 matrix, one gives a row vector. Whitespace is ignored.
+pub fn einsum<T>(spec : &str, operands : &[&Matrix<T>]) -> Result<Matrix<T>, MatrixError>
+    where T : AddAssign+Clone+Default+Mul<Output=T> {
+    let spec : String = spec.chars().filter(|c| !c.is_whitespace()).collect();
+    let (inputs, output) = match spec.split_once("->") {
+        Some(parts) => parts,
+        None => return invalid("expected \"->\" before the output subscripts".into()),
+    };
+    let inputs : Vec<Vec<char>> = inputs.split(',').map(|s| s.chars().collect()).collect();
+    let output : Vec<char> = output.chars().collect();
+
+    if inputs.len() != operands.len() {
+        return invalid(format!("{} subscript groups for {} operands", inputs.len(), operands.len()));
+    }
+    if output.len() > 2 {
+        return invalid(format!("{} output subscripts, at most two are supported", output.len()));
+    }
+
+    // every distinct letter, with the extent it runs over
+    let mut letters : Vec<char> = Vec::new();
+    let mut extents : Vec<usize> = Vec::new();
+    for (k, (subscripts, m)) in inputs.iter().zip(operands.iter()).enumerate() {
+        if subscripts.len() != 2 {
+            return invalid(format!("operand {} needs two subscripts, got {}", k, subscripts.len()));
+        }
+        for (&c, &extent) in subscripts.iter().zip([m.rows, m.cols].iter()) {
+            if !c.is_ascii_alphabetic() {
+                return invalid(format!("'{}' is not a subscript letter", c));
+            }
+            match letters.iter().position(|&l| l == c) {
+                Some(p) if extents[p] != extent => {
+                    return invalid(format!("'{}' runs over both {} and {}", c, extents[p], extent));
+                }
+                Some(_) => {}
+                None => {
+                    letters.push(c);
+                    extents.push(extent);
+                }
+            }
+        }
+    }
+
+    let position = |c : &char| letters.iter().position(|l| l == c);
+    let mut output_axes = Vec::new();
+    for c in output.iter() {
+        match position(c) {
+            Some(p) if !output_axes.contains(&p) => output_axes.push(p),
+            Some(_) => return invalid(format!("'{}' appears twice in the output", c)),
+            None => return invalid(format!("output subscript '{}' isn't used by any operand", c)),
+        }
+    }
+    let operand_axes : Vec<(usize, usize)> = inputs.iter()
+        .map(|s| (position(&s[0]).unwrap(), position(&s[1]).unwrap()))
+        .collect();
+
+    let (rows, cols) = match output_axes[..] {
+        [] => (1, 1),
+        [j] => (1, extents[j]),
+        [i, j, ..] => (extents[i], extents[j]),
+    };
+    let mut result = Matrix::new(rows, cols);
+    if extents.contains(&0) {
+        return Ok(result);
+    }
+
+    // walk every assignment of values to the letters like an odometer,
+    // adding each product of operand entries into its output entry
+    let mut index = vec![0 ; letters.len()];
+    loop {
+        let mut products = operands.iter().zip(operand_axes.iter())
+            .map(|(m, &(a, b))| m.m[index[a]][index[b]].clone());
+        let first = products.next().unwrap();
+        let term = products.fold(first, |acc, x| acc * x);
+
+        let (i, j) = match output_axes[..] {
+            [] => (0, 0),
+            [j] => (0, index[j]),
+            [i, j, ..] => (index[i], index[j]),
+        };
+        result.m[i][j] += term;
+
+        let mut k = 0;
+        loop {
+            if k == index.len() {
+                return Ok(result);
+            }
+            index[k] += 1;
+            if index[k] < extents[k] {
+                break;
+            }
+            index[k] = 0;
+            k += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::einsum;
+    use crate::{Matrix, MatrixError};
+
+    #[test]
+    fn einsum_operations() {
+        let a = Matrix::from([[1,2,3],[4,5,6]]);
+        let b = Matrix::from([[1,0],[2,1],[0,-1]]);
+        let s = Matrix::from([[2,7],[1,8]]);
+
+        assert_eq!(einsum("ij,jk->ik", &[&a, &b]).unwrap(), &a * &b);
+        assert_eq!(einsum("ij,kj->ik", &[&a, &a]).unwrap(), a.mul_transpose(&a));
+
+        let mut t = a.clone();
+        t.transpose();
+        assert_eq!(einsum("ij->ji", &[&a]).unwrap(), t);
+
+        assert_eq!(einsum("ii->", &[&s]).unwrap(), Matrix::from([[10]]));
+        assert_eq!(einsum("ii->i", &[&s]).unwrap(), Matrix::from([[2,8]]));
+        assert_eq!(einsum("ij, ij -> ij", &[&s, &s]).unwrap(), Matrix::from([[4,49],[1,64]]));
+        assert_eq!(einsum("ij,ij->", &[&s, &s]).unwrap(), Matrix::from([[118]]));
+        assert_eq!(einsum("ij->j", &[&a]).unwrap(), Matrix::from([[5,7,9]]));
+
+        // a chain of three, summing over two letters at once
+        assert_eq!(einsum("ij,jk,kl->il", &[&a, &b, &s]).unwrap(), &(&a * &b) * &s);
+    }
+
+    #[test]
+    fn einsum_errors() {
+        let a = Matrix::from([[1,2,3],[4,5,6]]);
+
+        let bad = |spec : &str| matches!(einsum(spec, &[&a, &a]), Err(MatrixError::InvalidSubscripts(_)));
+        assert!(bad("ij,jk"));
+        assert!(bad("ij,jk->ik"));
+        assert!(bad("ij->ij"));
+        assert!(bad("ijk,ij->i"));
+        assert!(bad("ij,ij->ii"));
+        assert!(bad("ij,ij->ik"));
+        assert!(!bad("ij,ij->ij"));
+    }
+}
@@ -0,0 +1,143 @@
+use num_traits::{Float, Signed};
+
+use crate::Matrix;
+
+impl<T : Clone+PartialOrd> Matrix<T> {
+    // Copy with every entry limited to [min, max]
+    pub fn clamp(&self, min : T, max : T) -> Matrix<T> {
+        let mut m = self.clone();
+        m.clamp_mut(min, max);
+        m
+    }
+
+    // Limits every entry to [min, max] in place. An entry which compares
+    // unordered with both bounds (a float NaN) is left as it is, so NaNs
+    // survive clamping; a NaN bound panics, as does min > max.
+    pub fn clamp_mut(&mut self, min : T, max : T) {
+        assert!(min <= max);
+
+        for x in self.m.iter_mut().flatten() {
+            if *x < min {
+                *x = min.clone();
+            } else if *x > max {
+                *x = max.clone();
+            }
+        }
+    }
+}
+
+// For any signed type: integers, floats, and also big integers and ratios
+impl<T : Signed> Matrix<T> {
+    pub fn abs(&self) -> Matrix<T> {
+        Matrix::from_fn(self.rows, self.cols, |i, j| self.m[i][j].abs())
+    }
+
+    pub fn abs_mut(&mut self) {
+        for x in self.m.iter_mut().flatten() {
+            *x = x.abs();
+        }
+    }
+
+    // -1, 0 or 1 by sign. Floats follow Float::signum: ±0.0 maps to ±1.0,
+    // and NaN stays NaN.
+    pub fn signum(&self) -> Matrix<T> {
+        Matrix::from_fn(self.rows, self.cols, |i, j| self.m[i][j].signum())
+    }
+
+    pub fn signum_mut(&mut self) {
+        for x in self.m.iter_mut().flatten() {
+            *x = x.signum();
+        }
+    }
+}
+
+// Element-wise float functions, each a loop over the contiguous row slices.
+// The operation is a generic parameter of apply rather than a function
+// pointer, so it is inlined into the loop; whether that loop vectorises
+// depends on the operation (exp, ln and powf are library calls).
+pub trait MatrixFloatExt {
+    fn exp(&self) -> Self;
+    fn ln(&self) -> Self;
+    fn sqrt(&self) -> Self;
+    fn powf(&self, p : f64) -> Self;
+    fn recip(&self) -> Self;
+}
+
+fn apply<T : Float, F : Fn(T) -> T>(a : &Matrix<T>, f : F) -> Matrix<T> {
+    let m = a.m.iter().map(|row| row.iter().map(|&x| f(x)).collect()).collect();
+    Matrix { m, rows : a.rows, cols : a.cols }
+}
+
+impl<T : Float> MatrixFloatExt for Matrix<T> {
+    fn exp(&self) -> Self {
+        apply(self, T::exp)
+    }
+
+    fn ln(&self) -> Self {
+        apply(self, T::ln)
+    }
+
+    fn sqrt(&self) -> Self {
+        apply(self, T::sqrt)
+    }
+
+    // p is converted to T once, up front
+    fn powf(&self, p : f64) -> Self {
+        let p = T::from(p).unwrap();
+        apply(self, move |x| x.powf(p))
+    }
+
+    fn recip(&self) -> Self {
+        apply(self, T::recip)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MatrixFloatExt;
+    use crate::Matrix;
+
+    #[test]
+    fn clamping() {
+        let a = Matrix::from([[-5,0,3],[7,12,255]]);
+        assert_eq!(a.clamp(0, 10), Matrix::from([[0,0,3],[7,10,10]]));
+
+        let mut f = Matrix::from([[f64::NAN,-1.5,0.25,f64::INFINITY]]);
+        f.clamp_mut(0.0, 1.0);
+        assert!(f[(0, 0)].is_nan());
+        assert_eq!(&f.row(0)[1..], [0.0,0.25,1.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn clamp_with_inverted_bounds() {
+        Matrix::from([[1]]).clamp(2, 1);
+    }
+
+    #[test]
+    fn absolute_value_and_sign() {
+        let a = Matrix::from([[-3,0,2],[5,-1,-7]]);
+        assert_eq!(a.abs(), Matrix::from([[3,0,2],[5,1,7]]));
+        assert_eq!(a.signum(), Matrix::from([[-1,0,1],[1,-1,-1]]));
+
+        let mut f = Matrix::from([[-2.5,0.5],[-0.0,4.0]]);
+        f.signum_mut();
+        assert_eq!(f, Matrix::from([[-1.0,1.0],[-1.0,1.0]]));
+        f.abs_mut();
+        assert_eq!(f, Matrix::from([[1.0,1.0],[1.0,1.0]]));
+    }
+
+    #[test]
+    fn float_functions() {
+        let a = Matrix::from([[1.0f64,4.0],[0.25,16.0]]);
+
+        assert_eq!(a.sqrt(), Matrix::from([[1.0,2.0],[0.5,4.0]]));
+        assert_eq!(a.recip(), Matrix::from([[1.0,0.25],[4.0,0.0625]]));
+        assert_eq!(a.powf(0.5), a.sqrt());
+        assert_eq!(a.ln().exp()[(1, 1)].round(), 16.0);
+
+        let f = Matrix::from([[1.0f32,8.0]]);
+        assert_eq!(f.powf(2.0), Matrix::from([[1.0,64.0]]));
+        assert_eq!(f.ln()[(0, 0)], 0.0);
+    }
+}
@@ -0,0 +1,217 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::Matrix;
+
+// Lazily evaluated element-wise expressions. `a.expr() + &b - c.expr()`
+// builds a small tree of nodes borrowing the operands, and nothing is
+// computed until `eval` (or `Matrix::assign`) walks the result once, working
+// out each entry straight from the leaves. Shapes are checked as the tree is
+// built, so a mismatch panics at the offending operator just as the eager
+// operators do.
+
+// Something which can produce entry (i, j) on demand
+pub trait Entries<T> {
+    fn entry(&self, i : usize, j : usize) -> T;
+}
+
+#[derive(Clone,Copy,Debug)]
+pub struct Expr<E> {
+    e : E,
+    rows : usize,
+    cols : usize,
+}
+
+#[derive(Clone,Copy,Debug)]
+pub struct MatrixRef<'a, T>(&'a Matrix<T>);
+
+#[derive(Clone,Copy,Debug)]
+pub struct Sum<A, B>(A, B);
+
+#[derive(Clone,Copy,Debug)]
+pub struct Difference<A, B>(A, B);
+
+// Element-wise (Hadamard) product
+#[derive(Clone,Copy,Debug)]
+pub struct Product<A, B>(A, B);
+
+#[derive(Clone,Copy,Debug)]
+pub struct Negated<A>(A);
+
+#[derive(Clone,Copy,Debug)]
+pub struct Scaled<A, S>(A, S);
+
+impl<T : Clone> Entries<T> for MatrixRef<'_, T> {
+    fn entry(&self, i : usize, j : usize) -> T {
+        self.0.m[i][j].clone()
+    }
+}
+
+impl<T : Add<Output=T>, A : Entries<T>, B : Entries<T>> Entries<T> for Sum<A, B> {
+    fn entry(&self, i : usize, j : usize) -> T {
+        self.0.entry(i, j) + self.1.entry(i, j)
+    }
+}
+
+impl<T : Sub<Output=T>, A : Entries<T>, B : Entries<T>> Entries<T> for Difference<A, B> {
+    fn entry(&self, i : usize, j : usize) -> T {
+        self.0.entry(i, j) - self.1.entry(i, j)
+    }
+}
+
+impl<T : Mul<Output=T>, A : Entries<T>, B : Entries<T>> Entries<T> for Product<A, B> {
+    fn entry(&self, i : usize, j : usize) -> T {
+        self.0.entry(i, j) * self.1.entry(i, j)
+    }
+}
+
+impl<T : Neg<Output=T>, A : Entries<T>> Entries<T> for Negated<A> {
+    fn entry(&self, i : usize, j : usize) -> T {
+        -self.0.entry(i, j)
+    }
+}
+
+impl<T : Clone+Mul<Output=T>, A : Entries<T>> Entries<T> for Scaled<A, T> {
+    fn entry(&self, i : usize, j : usize) -> T {
+        self.0.entry(i, j) * self.1.clone()
+    }
+}
+
+impl<T> Matrix<T> {
+    // Starts a lazy expression borrowing this matrix
+    pub fn expr(&self) -> Expr<MatrixRef<'_, T>> {
+        Expr { e : MatrixRef(self), rows : self.rows, cols : self.cols }
+    }
+
+    // Evaluates an expression straight into this matrix's existing storage.
+    // The expression can't borrow self, so this never aliases.
+    pub fn assign<E : Entries<T>>(&mut self, expr : Expr<E>) {
+        assert!(self.rows == expr.rows);
+        assert!(self.cols == expr.cols);
+
+        for (i, row) in self.m.iter_mut().enumerate() {
+            for (j, x) in row.iter_mut().enumerate() {
+                *x = expr.e.entry(i, j);
+            }
+        }
+    }
+}
+
+impl<E> Expr<E> {
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    // Works out every entry in a single pass
+    pub fn eval<T>(&self) -> Matrix<T> where E : Entries<T> {
+        Matrix::from_fn(self.rows, self.cols, |i, j| self.e.entry(i, j))
+    }
+
+    pub fn scale<T>(self, s : T) -> Expr<Scaled<E, T>> {
+        Expr { e : Scaled(self.e, s), rows : self.rows, cols : self.cols }
+    }
+
+    pub fn component_mul<F>(self, other : Expr<F>) -> Expr<Product<E, F>> {
+        self.check_shape(other.rows, other.cols);
+        Expr { e : Product(self.e, other.e), rows : self.rows, cols : self.cols }
+    }
+
+    fn check_shape(&self, rows : usize, cols : usize) {
+        // element-wise operations need matrices of the same size
+        assert!(self.rows == rows);
+        assert!(self.cols == cols);
+    }
+}
+
+impl<T, E : Entries<T>> From<Expr<E>> for Matrix<T> {
+    fn from(expr : Expr<E>) -> Self {
+        expr.eval()
+    }
+}
+
+impl<E, F> Add<Expr<F>> for Expr<E> {
+    type Output = Expr<Sum<E, F>>;
+
+    fn add(self, other : Expr<F>) -> Self::Output {
+        self.check_shape(other.rows, other.cols);
+        Expr { e : Sum(self.e, other.e), rows : self.rows, cols : self.cols }
+    }
+}
+
+impl<'a, E, T> Add<&'a Matrix<T>> for Expr<E> {
+    type Output = Expr<Sum<E, MatrixRef<'a, T>>>;
+
+    fn add(self, other : &'a Matrix<T>) -> Self::Output {
+        self + other.expr()
+    }
+}
+
+impl<E, F> Sub<Expr<F>> for Expr<E> {
+    type Output = Expr<Difference<E, F>>;
+
+    fn sub(self, other : Expr<F>) -> Self::Output {
+        self.check_shape(other.rows, other.cols);
+        Expr { e : Difference(self.e, other.e), rows : self.rows, cols : self.cols }
+    }
+}
+
+impl<'a, E, T> Sub<&'a Matrix<T>> for Expr<E> {
+    type Output = Expr<Difference<E, MatrixRef<'a, T>>>;
+
+    fn sub(self, other : &'a Matrix<T>) -> Self::Output {
+        self - other.expr()
+    }
+}
+
+impl<E> Neg for Expr<E> {
+    type Output = Expr<Negated<E>>;
+
+    fn neg(self) -> Self::Output {
+        Expr { e : Negated(self.e), rows : self.rows, cols : self.cols }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Matrix;
+
+    #[test]
+    fn fused_expression_matches_eager() {
+        let a = Matrix::from([[1,2,3],[4,5,6]]);
+        let b = Matrix::from([[6,5,4],[3,2,1]]);
+        let c = Matrix::from([[1,0,1],[0,1,0]]);
+
+        let lazy = (a.expr() + &b - c.expr()).scale(2).eval();
+        assert_eq!(lazy, Matrix::from([[12,14,12],[14,12,14]]));
+        assert_eq!(lazy, (&(&a + &b) + &Matrix::from([[-1,0,-1],[0,-1,0]])).expr().scale(2).eval());
+
+        let hadamard : Matrix<i32> = (-a.expr()).component_mul(b.expr()).into();
+        assert_eq!(hadamard, Matrix::from([[-6,-10,-12],[-12,-10,-6]]));
+    }
+
+    #[test]
+    fn assign_reuses_storage() {
+        let a = Matrix::from([[1.0,2.0],[3.0,4.0]]);
+        let b = Matrix::from([[0.5,0.5],[0.5,0.5]]);
+        let mut out = Matrix::from([[0.0,0.0],[0.0,0.0]]);
+
+        out.assign(a.expr() - &b);
+        assert_eq!(out, Matrix::from([[0.5,1.5],[2.5,3.5]]));
+
+        // the previous contents can feed the next expression via a copy
+        let previous = out.clone();
+        out.assign(previous.expr().component_mul(a.expr()) + &b);
+        assert_eq!(out, Matrix::from([[1.0,3.5],[8.0,14.5]]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn mismatched_expression_shapes() {
+        let a = Matrix::from([[1,2]]);
+        let b = Matrix::from([[1],[2]]);
+        let _ = a.expr() + &b;
+    }
+}
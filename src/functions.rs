@@ -0,0 +1,124 @@
+use std::ops::AddAssign;
+
+use num_traits::Float;
+
+use crate::Matrix;
+
+// Frobenius norm of A - B
+fn distance<T : Float>(a : &Matrix<T>, b : &Matrix<T>) -> T {
+    a.m.iter().flatten().zip(b.m.iter().flatten())
+        .fold(T::zero(), |acc, (&x, &y)| acc + (x - y) * (x - y))
+        .sqrt()
+}
+
+fn frobenius<T : Float>(a : &Matrix<T>) -> T {
+    a.m.iter().flatten().fold(T::zero(), |acc, &x| acc + x * x).sqrt()
+}
+
+fn scaled<T : Float>(a : &Matrix<T>, s : T) -> Matrix<T> {
+    Matrix::from_fn(a.rows, a.cols, |i, j| a.m[i][j] * s)
+}
+
+// Matrix functions. These lean on `*` and `inverse`, which want
+// T : AddAssign+Default on top of Float; f32 and f64 have both.
+impl<T : Float+AddAssign+Default> Matrix<T> {
+    // Principal square root by the Denman-Beavers iteration
+    //     Y ← (Y + Z⁻¹)/2,  Z ← (Z + Y⁻¹)/2
+    // which starts from Y = A, Z = I and converges to Y = √A, Z = √A⁻¹.
+    // Returns None if an iterate is singular or the iteration stalls, which
+    // happens when A has eigenvalues on the closed negative real axis.
+    pub fn sqrtm(&self) -> Option<Matrix<T>> {
+        assert!(self.is_square());
+
+        let half = (T::one() + T::one()).recip();
+        let tol = T::epsilon().sqrt() * T::epsilon().sqrt().sqrt();
+        let mut y = self.clone();
+        let mut z = Matrix::identity(self.rows);
+
+        for _ in 0..100 {
+            let y_inv = y.inverse()?;
+            let z_inv = z.inverse()?;
+            let next = scaled(&(&y + &z_inv), half);
+            z = scaled(&(&z + &y_inv), half);
+
+            let change = distance(&next, &y);
+            y = next;
+            if change <= tol * frobenius(&y) {
+                return Some(y);
+            }
+        }
+
+        None
+    }
+
+    // Principal logarithm by inverse scaling and squaring: take square roots
+    // until X = A^(1/2^k) is close to I, sum the Mercator series for
+    // log(I + E) with E = X - I, then scale back up by 2^k. Returns None in
+    // the same cases as `sqrtm`.
+    pub fn logm(&self) -> Option<Matrix<T>> {
+        assert!(self.is_square());
+
+        let n = self.rows;
+        let identity = Matrix::identity(n);
+        let quarter = (T::one() + T::one() + T::one() + T::one()).recip();
+
+        let mut x = self.clone();
+        let mut scale = T::one();
+        while distance(&x, &identity) > quarter {
+            x = x.sqrtm()?;
+            scale = scale + scale;
+        }
+
+        // log(I + E) = E - E²/2 + E³/3 - ..., with ‖E‖ ≤ 1/4
+        let e = Matrix::from_fn(n, n, |i, j| x.m[i][j] - identity.m[i][j]);
+        let mut power = e.clone();
+        let mut log = e.clone();
+        let mut k = T::one();
+        for term in 2..60 {
+            power = &power * &e;
+            k += T::one();
+            let sign = if term % 2 == 0 { -T::one() } else { T::one() };
+            let step = scaled(&power, sign / k);
+            log = &log + &step;
+            if frobenius(&step) <= T::epsilon() * frobenius(&log) {
+                break;
+            }
+        }
+
+        Some(scaled(&log, scale))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{assert_close, Matrix};
+
+    #[test]
+    fn matrix_square_root() {
+        let a = Matrix::from([[33.0,24.0],[48.0,57.0]]);
+        assert_close(&a.sqrtm().unwrap(), &Matrix::from([[5.0,2.0],[4.0,7.0]]), 1e-10);
+
+        let b = Matrix::from([[4.0,1.0,0.0],[1.0,3.0,1.0],[0.0,1.0,2.0]]);
+        let r = b.sqrtm().unwrap();
+        assert_close(&(&r * &r), &b, 1e-10);
+
+        assert_eq!(Matrix::from([[-1.0,0.0],[0.0,1.0]]).sqrtm(), None);
+    }
+
+    #[test]
+    fn matrix_logarithm() {
+        let e = 1f64.exp();
+        let d = Matrix::from([[e,0.0],[0.0,e * e]]);
+        assert_close(&d.logm().unwrap(), &Matrix::from([[1.0,0.0],[0.0,2.0]]), 1e-10);
+
+        // log of a rotation by θ is the generator θ·[[0,-1],[1,0]]
+        let t = 0.7f64;
+        let r = Matrix::from([[t.cos(),-t.sin()],[t.sin(),t.cos()]]);
+        assert_close(&r.logm().unwrap(), &Matrix::from([[0.0,-t],[t,0.0]]), 1e-10);
+
+        // a Jordan block: log [[2,1],[0,2]] = [[ln 2,1/2],[0,ln 2]]
+        let j = Matrix::from([[2.0,1.0],[0.0,2.0]]);
+        let l2 = 2f64.ln();
+        assert_close(&j.logm().unwrap(), &Matrix::from([[l2,0.5],[0.0,l2]]), 1e-10);
+    }
+}
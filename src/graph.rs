@@ -0,0 +1,142 @@
+use std::ops::Add;
+
+use crate::semiring::{Boolean, MinPlus};
+use crate::Matrix;
+
+// Boolean algebra on adjacency matrices: OR plays the role of addition and
+// AND of multiplication, so entry (i, j) of the k-th boolean power records
+// whether there is a walk of length k from i to j.
+impl Matrix<bool> {
+    // Entrywise OR
+    pub fn boolean_add(&self, other : &Matrix<bool>) -> Matrix<bool> {
+        assert!(self.rows == other.rows);
+        assert!(self.cols == other.cols);
+
+        Matrix::from_fn(self.rows, self.cols, |i, j| self.m[i][j] || other.m[i][j])
+    }
+
+    // Product with OR as addition and AND as multiplication
+    pub fn boolean_mul(&self, other : &Matrix<bool>) -> Matrix<bool> {
+        self.mul_with(other, &Boolean)
+    }
+
+    // Reachability by Warshall's algorithm: (i, j) is set when there is a
+    // walk of length at least one from i to j
+    pub fn transitive_closure(&self) -> Matrix<bool> {
+        assert!(self.is_square());
+
+        let mut r = self.m.clone();
+        for k in 0..self.rows {
+            for i in 0..self.rows {
+                if !r[i][k] {
+                    continue;
+                }
+                for j in 0..self.cols {
+                    r[i][j] = r[i][j] || r[k][j];
+                }
+            }
+        }
+
+        Matrix { m : r, rows : self.rows, cols : self.cols }
+    }
+}
+
+// Tropical (min, +) algebra on weighted adjacency matrices: None stands for
+// a missing edge (an infinite distance), min plays the role of addition and
+// + of multiplication.
+impl<T : Clone+Default+PartialOrd+Add<Output=T>> Matrix<Option<T>> {
+    // Product over the (min, +) semiring: entry (i, j) is the cheapest
+    // i → k → j over all k
+    pub fn min_plus_mul(&self, other : &Matrix<Option<T>>) -> Matrix<Option<T>> {
+        self.mul_with(other, &MinPlus)
+    }
+
+    // Shortest distances between every pair of vertices, by repeatedly
+    // squaring the matrix with zero-cost self loops added, so O(n³ log n).
+    // None marks an unreachable vertex. Assumes there are no negative
+    // cycles.
+    pub fn all_pairs_shortest_paths(&self) -> Matrix<Option<T>> {
+        assert!(self.is_square());
+
+        let n = self.rows;
+        let mut d = self.clone();
+        for i in 0..n {
+            if d.m[i][i].as_ref().is_none_or(|x| *x > T::default()) {
+                d.m[i][i] = Some(T::default());
+            }
+        }
+
+        // paths use at most n - 1 edges
+        let mut edges = 1;
+        while edges + 1 < n {
+            d = d.min_plus_mul(&d);
+            edges *= 2;
+        }
+        d
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Matrix;
+
+    #[test]
+    fn boolean_matrix_algebra() {
+        let a = Matrix::from([[false,true,false],[false,false,true],[false,false,false]]);
+        let b = Matrix::from([[true,false,false],[false,false,false],[false,true,false]]);
+
+        assert_eq!(a.boolean_add(&b), Matrix::from([[true,true,false],[false,false,true],[false,true,false]]));
+
+        // walks of length two: 0 → 1 → 2
+        assert_eq!(a.boolean_mul(&a), Matrix::from([[false,false,true],[false,false,false],[false,false,false]]));
+    }
+
+    #[test]
+    fn reachability() {
+        // 0 → 1 → 2 → 0 is a cycle, 3 only reaches into it
+        let mut a = Matrix::from([[false ; 4] ; 4]);
+        for &(i, j) in [(0, 1), (1, 2), (2, 0), (3, 1)].iter() {
+            a[i][j] = true;
+        }
+
+        let closure = a.transitive_closure();
+        for i in 0..4 {
+            for j in 0..4 {
+                assert_eq!(closure[(i, j)], j != 3, "{} → {}", i, j);
+            }
+        }
+
+        // the closure is the OR of the first n boolean powers
+        let mut power = a.clone();
+        let mut total = a.clone();
+        for _ in 1..4 {
+            power = power.boolean_mul(&a);
+            total = total.boolean_add(&power);
+        }
+        assert_eq!(total, closure);
+    }
+
+    #[test]
+    fn shortest_paths() {
+        let a = Matrix::from([
+            [None,    Some(4), Some(1), None],
+            [None,    None,    None,    Some(1)],
+            [None,    Some(2), None,    Some(5)],
+            [Some(3), None,    None,    None],
+        ]);
+
+        assert_eq!(a.min_plus_mul(&a)[(0, 1)], Some(3));
+
+        let d = a.all_pairs_shortest_paths();
+        assert_eq!(d, Matrix::from([
+            [Some(0), Some(3), Some(1), Some(4)],
+            [Some(4), Some(0), Some(5), Some(1)],
+            [Some(6), Some(2), Some(0), Some(3)],
+            [Some(3), Some(6), Some(4), Some(0)],
+        ]));
+
+        // a vertex with no edges in stays unreachable
+        let b = Matrix::from([[None, Some(1.5)], [None, None]]);
+        assert_eq!(b.all_pairs_shortest_paths(), Matrix::from([[Some(0.0), Some(1.5)], [None, Some(0.0)]]));
+    }
+}
@@ -0,0 +1,71 @@
+use half::{bf16, f16};
+
+use crate::Matrix;
+
+// Half precision storage: f16 (IEEE binary16, 11 bit significand) and bf16
+// (8 bit significand with f32's exponent range) take half the memory of
+// f32. With half's num-traits support they are Floats, so the generic float
+// methods apply, but sums of many products are best formed in f32.
+impl Matrix<f32> {
+    // Rounds every entry to the nearest f16, overflowing to ±∞
+    pub fn to_f16(&self) -> Matrix<f16> {
+        Matrix::from_fn(self.rows, self.cols, |i, j| f16::from_f32(self.m[i][j]))
+    }
+
+    // Rounds every entry to the nearest bf16
+    pub fn to_bf16(&self) -> Matrix<bf16> {
+        Matrix::from_fn(self.rows, self.cols, |i, j| bf16::from_f32(self.m[i][j]))
+    }
+}
+
+// Mixed precision products: half precision inputs, an f32 result. Each
+// f16 or bf16 product is exact in f32, so only the accumulation rounds,
+// and at f32's 24 bits rather than the inputs' 11 or 8. These are
+// mul_accumulate::<f32> under a shorter name.
+impl Matrix<f16> {
+    pub fn mul_f32(&self, other : &Matrix<f16>) -> Matrix<f32> {
+        self.mul_accumulate::<f32>(other)
+    }
+}
+
+impl Matrix<bf16> {
+    pub fn mul_f32(&self, other : &Matrix<bf16>) -> Matrix<f32> {
+        self.mul_accumulate::<f32>(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use half::f16;
+
+    use crate::Matrix;
+
+    #[test]
+    fn half_precision_round_trip() {
+        let a = Matrix::from([[1.0f32,0.5,-2.25],[1.0e5,0.1,3.0]]);
+
+        let h = a.to_f16();
+        assert_eq!(h[(0, 2)], f16::from_f32(-2.25));
+        assert!(h[(1, 0)].is_infinite());
+        assert!((h.cast::<f32>()[(1, 1)] - 0.1).abs() < 1e-4);
+
+        let b = a.to_bf16();
+        assert!((b.cast::<f32>()[(1, 0)] - 1.0e5).abs() < 1.0e3);
+        assert_eq!(b.cast::<f32>()[(0, 1)], 0.5);
+    }
+
+    #[test]
+    fn half_precision_products() {
+        // a dot product of 4096 ones: in f16 the running sum sticks at 2048,
+        // where 2049 isn't representable and the tie rounds back down
+        let n = 4096;
+        let row = Matrix::from_fn(1, n, |_, _| f16::from_f32(1.0));
+        let col = Matrix::from_fn(n, 1, |_, _| f16::from_f32(1.0));
+
+        assert_eq!(row.mul_f32(&col)[(0, 0)], 4096.0);
+        assert_eq!((&row * &col)[(0, 0)].to_f32(), 2048.0);
+
+        let a = Matrix::from([[1.0f32,2.0],[3.0,4.0]]).to_bf16();
+        assert_eq!(a.mul_f32(&a), Matrix::from([[7.0,10.0],[15.0,22.0]]));
+    }
+}
@@ -0,0 +1,73 @@
+use num_traits::Float;
+
+use crate::Matrix;
+
+impl<T : Float> Matrix<T> {
+    // Reduces a square matrix to upper Hessenberg form H = QᵀAQ by Householder
+    // similarity transforms, returning H and the orthogonal Q. Symmetric
+    // matrices come out tridiagonal. Eigenvalue iterations on H cost O(n²)
+    // per step rather than O(n³).
+    pub fn hessenberg(&self) -> (Matrix<T>, Matrix<T>) {
+        assert!(self.is_square());
+
+        let n = self.rows;
+        let mut h = self.clone();
+        let mut q = Matrix::from_fn(n, n, |i, j| if i == j { T::one() } else { T::zero() });
+
+        for k in 0..n.saturating_sub(2) {
+            // reflector taking h[k+1.., k] onto a multiple of e1, acting on
+            // coordinates k+1.. only
+            let mut v : Vec<T> = (0..n).map(|i| if i > k { h.m[i][k] } else { T::zero() }).collect();
+            let norm = v.iter().fold(T::zero(), |acc, &x| acc + x * x).sqrt();
+            if norm == T::zero() {
+                continue;
+            }
+            let alpha = if v[k + 1] > T::zero() { -norm } else { norm };
+            v[k + 1] = v[k + 1] - alpha;
+
+            h.apply_householder_left(&v);
+            h.apply_householder_right(&v);
+            q.apply_householder_right(&v);
+
+            // these are zero up to rounding
+            h.m[k + 1][k] = alpha;
+            for i in (k + 2)..n {
+                h.m[i][k] = T::zero();
+            }
+        }
+
+        (h, q)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{assert_close, Matrix};
+
+    #[test]
+    fn hessenberg_reduction_is_a_similarity() {
+        let a = Matrix::from([[4.0f64,1.0,-2.0,2.0],[1.0,2.0,0.0,1.0],[-2.0,0.0,3.0,-2.0],[2.0,1.0,-2.0,-1.0]]);
+        let (h, q) = a.hessenberg();
+
+        assert!(q.is_orthogonal(1e-12));
+        for i in 2..4 {
+            for j in 0..(i - 1) {
+                assert_eq!(h[(i, j)], 0.0);
+            }
+        }
+        let mut qt = q.clone();
+        qt.transpose();
+        assert_close(&(&(&qt * &a) * &q), &h, 1e-12);
+    }
+
+    #[test]
+    fn symmetric_hessenberg_is_tridiagonal() {
+        let a = Matrix::from([[2.0f64,-1.0,0.5,1.0],[-1.0,3.0,1.0,0.0],[0.5,1.0,1.0,2.0],[1.0,0.0,2.0,4.0]]);
+        let (h, q) = a.hessenberg();
+
+        assert!(h.is_tridiagonal(1e-12));
+        assert!(q.is_orthogonal(1e-12));
+        let trace = |m : &Matrix<f64>| (0..4).map(|i| m[(i, i)]).sum::<f64>();
+        assert!((trace(&h) - trace(&a)).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,216 @@
+use std::ops::{Add, AddAssign, Div, Mul};
+
+use num_traits::One;
+
+use crate::Matrix;
+
+// How `convolve` treats the border of the grid
+#[derive(Clone,Copy,Debug,PartialEq,Eq)]
+pub enum Padding {
+    // only positions where the kernel fits entirely inside the grid
+    Valid,
+    // zero padding so that, before striding, the output is the size of the
+    // input, with the kernel centred on each entry
+    Same,
+}
+
+// Where a kernel of the given size lands on a rows × cols grid: the output
+// size, and offsets such that output (i, j) reads input
+//     (i·stride + row_offset - a, j·stride + col_offset - b)
+// for kernel entry (a, b). Reads outside the grid are zero padding.
+struct Geometry {
+    rows : usize,
+    cols : usize,
+    row_offset : usize,
+    col_offset : usize,
+    stride : usize,
+}
+
+impl Geometry {
+    fn new(rows : usize, cols : usize, kernel : (usize, usize), padding : Padding, stride : usize) -> Self {
+        assert!(stride > 0);
+        assert!(kernel.0 > 0 && kernel.1 > 0);
+
+        match padding {
+            Padding::Valid => {
+                assert!(kernel.0 <= rows && kernel.1 <= cols);
+                Geometry {
+                    rows : (rows - kernel.0) / stride + 1,
+                    cols : (cols - kernel.1) / stride + 1,
+                    row_offset : kernel.0 - 1,
+                    col_offset : kernel.1 - 1,
+                    stride,
+                }
+            }
+            Padding::Same => Geometry {
+                rows : rows.div_ceil(stride),
+                cols : cols.div_ceil(stride),
+                row_offset : kernel.0 / 2,
+                col_offset : kernel.1 / 2,
+                stride,
+            },
+        }
+    }
+
+    // Input position read by output (i, j) for kernel entry (a, b), if it
+    // falls inside a rows × cols grid
+    fn source(&self, i : usize, j : usize, a : usize, b : usize, rows : usize, cols : usize) -> Option<(usize, usize)> {
+        let r = (i * self.stride + self.row_offset).checked_sub(a).filter(|&r| r < rows)?;
+        let c = (j * self.stride + self.col_offset).checked_sub(b).filter(|&c| c < cols)?;
+        Some((r, c))
+    }
+}
+
+impl<T : AddAssign+Clone+Default+Mul<Output=T>> Matrix<T> {
+    // 2D convolution of the matrix (as an image or grid) with a kernel,
+    // sampling every stride-th output position in each direction. This is
+    // true convolution, with the kernel flipped; flip the kernel first to
+    // get cross-correlation instead.
+    pub fn convolve(&self, kernel : &Matrix<T>, padding : Padding, stride : usize) -> Matrix<T> {
+        let g = Geometry::new(self.rows, self.cols, (kernel.rows, kernel.cols), padding, stride);
+
+        Matrix::from_fn(g.rows, g.cols, |i, j| {
+            let mut entry = T::default();
+            for a in 0..kernel.rows {
+                for b in 0..kernel.cols {
+                    if let Some((r, c)) = g.source(i, j, a, b, self.rows, self.cols) {
+                        entry += kernel.m[a][b].clone() * self.m[r][c].clone();
+                    }
+                }
+            }
+            entry
+        })
+    }
+}
+
+impl<T : Clone+Default> Matrix<T> {
+    // Patch matrix for a convolution: one row per output position (in
+    // row-major order) and one column per kernel entry, so that
+    // im2col · vec(kernel) (the kernel flattened row-major into a column)
+    // equals `convolve` with the same arguments, flattened row-major.
+    pub fn im2col(&self, kernel_shape : (usize, usize), stride : usize, padding : Padding) -> Matrix<T> {
+        let g = Geometry::new(self.rows, self.cols, kernel_shape, padding, stride);
+        let (kr, kc) = kernel_shape;
+
+        Matrix::from_fn(g.rows * g.cols, kr * kc, |p, q| {
+            match g.source(p / g.cols, p % g.cols, q / kc, q % kc, self.rows, self.cols) {
+                Some((r, c)) => self.m[r][c].clone(),
+                None => T::default(),
+            }
+        })
+    }
+}
+
+// How `pool` combines the entries of a block
+#[derive(Clone,Copy,Debug,PartialEq,Eq)]
+pub enum PoolKind {
+    Max,
+    Mean,
+}
+
+// What `pool` does with leftover rows/columns when the block size doesn't
+// divide the matrix size
+#[derive(Clone,Copy,Debug,PartialEq,Eq)]
+pub enum RaggedEdges {
+    // drop them, keeping only complete blocks
+    Discard,
+    // pool them as smaller blocks
+    Partial,
+}
+
+impl<T : Clone+PartialOrd+One+Add<Output=T>+Div<Output=T>> Matrix<T> {
+    // Down-samples by combining each non-overlapping block_rows × block_cols
+    // block into a single entry
+    pub fn pool(&self, block_rows : usize, block_cols : usize, kind : PoolKind, edges : RaggedEdges) -> Matrix<T> {
+        assert!(block_rows > 0 && block_cols > 0);
+
+        let (rows, cols) = match edges {
+            RaggedEdges::Discard => (self.rows / block_rows, self.cols / block_cols),
+            RaggedEdges::Partial => (self.rows.div_ceil(block_rows), self.cols.div_ceil(block_cols)),
+        };
+
+        Matrix::from_fn(rows, cols, |i, j| {
+            let mut block = self.m[i * block_rows..self.rows.min((i + 1) * block_rows)].iter()
+                .flat_map(|row| row[j * block_cols..self.cols.min((j + 1) * block_cols)].iter());
+
+            // every block has at least one entry
+            let first = block.next().unwrap().clone();
+            match kind {
+                PoolKind::Max => block.fold(first, |acc, x| if *x > acc { x.clone() } else { acc }),
+                PoolKind::Mean => {
+                    let (total, count) = block.fold((first, T::one()), |(total, count), x| {
+                        (total + x.clone(), count + T::one())
+                    });
+                    total / count
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Padding, PoolKind, RaggedEdges};
+    use crate::Matrix;
+
+    #[test]
+    fn valid_convolution() {
+        let x = Matrix::from([[1,2,3,0],[4,5,6,1],[7,8,9,2]]);
+
+        // the kernel is flipped, so this picks out x(i + 1, j + 1) - x(i, j)
+        let k = Matrix::from([[1,0],[0,-1]]);
+        assert_eq!(x.convolve(&k, Padding::Valid, 1), Matrix::from([[4,4,-2],[4,4,-4]]));
+
+        let ones = Matrix::from([[1,1],[1,1]]);
+        assert_eq!(x.convolve(&ones, Padding::Valid, 2), Matrix::from([[12,10]]));
+    }
+
+    #[test]
+    fn same_convolution() {
+        let x = Matrix::from([[1,2,3],[4,5,6],[7,8,9]]);
+        let box_blur = Matrix::from([[1,1,1],[1,1,1],[1,1,1]]);
+
+        assert_eq!(x.convolve(&box_blur, Padding::Same, 1),
+                   Matrix::from([[12,21,16],[27,45,33],[24,39,28]]));
+        assert_eq!(x.convolve(&box_blur, Padding::Same, 2), Matrix::from([[12,16],[24,28]]));
+
+        // a single-entry kernel is the identity
+        assert_eq!(x.convolve(&Matrix::from([[1]]), Padding::Same, 1), x);
+    }
+
+    #[test]
+    fn block_pooling() {
+        let x = Matrix::from([[1.0,2.0,5.0,6.0,9.0],
+                              [3.0,4.0,7.0,8.0,0.0],
+                              [0.0,1.0,2.0,3.0,4.0]]);
+
+        assert_eq!(x.pool(2, 2, PoolKind::Max, RaggedEdges::Discard), Matrix::from([[4.0,8.0]]));
+        assert_eq!(x.pool(2, 2, PoolKind::Mean, RaggedEdges::Discard), Matrix::from([[2.5,6.5]]));
+
+        assert_eq!(x.pool(2, 2, PoolKind::Max, RaggedEdges::Partial),
+                   Matrix::from([[4.0,8.0,9.0],[1.0,3.0,4.0]]));
+        assert_eq!(x.pool(2, 2, PoolKind::Mean, RaggedEdges::Partial),
+                   Matrix::from([[2.5,6.5,4.5],[0.5,2.5,4.0]]));
+    }
+
+    #[test]
+    fn im2col_matches_convolve() {
+        let x = Matrix::from([[1,2,3,0],[4,5,6,1],[7,8,9,2]]);
+        let k = Matrix::from([[1,-2,0],[3,1,-1]]);
+
+        for &padding in [Padding::Valid, Padding::Same].iter() {
+            for stride in 1..3 {
+                let direct = x.convolve(&k, padding, stride);
+                let patches = x.im2col((2, 3), stride, padding);
+                let flat_kernel = Matrix::from(vec![k.clone().into_row_major_vec()]);
+
+                let product = patches.mul_transpose(&flat_kernel);
+                assert_eq!(product.into_row_major_vec(), direct.into_row_major_vec());
+            }
+        }
+
+        // each row of a stride-1 valid patch matrix is a window, flipped
+        let p = Matrix::from([[1,2],[3,4]]).im2col((1, 2), 1, Padding::Valid);
+        assert_eq!(p, Matrix::from([[2,1],[4,3]]));
+    }
+}
@@ -0,0 +1,288 @@
+use num_integer::Integer;
+use num_traits::Signed;
+
+use crate::Matrix;
+
+// Row and column operations on an integer matrix which also record
+// themselves in the accompanying unimodular transforms, so that
+// u · original · v = a holds throughout
+struct Tracked<T> {
+    a : Vec<Vec<T>>,
+    u : Vec<Vec<T>>,
+    v : Vec<Vec<T>>,
+}
+
+fn identity<T : Integer+Clone>(n : usize) -> Vec<Vec<T>> {
+    (0..n).map(|i| (0..n).map(|j| if i == j { T::one() } else { T::zero() }).collect()).collect()
+}
+
+impl<T : Integer+Signed+Clone> Tracked<T> {
+    fn new(a : &Matrix<T>) -> Self {
+        Tracked { a : a.m.clone(), u : identity(a.rows), v : identity(a.cols) }
+    }
+
+    fn swap_rows(&mut self, i : usize, j : usize) {
+        self.a.swap(i, j);
+        self.u.swap(i, j);
+    }
+
+    fn swap_cols(&mut self, i : usize, j : usize) {
+        for row in self.a.iter_mut().chain(self.v.iter_mut()) {
+            row.swap(i, j);
+        }
+    }
+
+    // row[target] -= q · row[source]
+    fn sub_row(&mut self, target : usize, source : usize, q : &T) {
+        for rows in [&mut self.a, &mut self.u].iter_mut() {
+            for j in 0..rows[target].len() {
+                let t = rows[source][j].clone() * q.clone();
+                rows[target][j] = rows[target][j].clone() - t;
+            }
+        }
+    }
+
+    // col[target] -= q · col[source]
+    fn sub_col(&mut self, target : usize, source : usize, q : &T) {
+        for row in self.a.iter_mut().chain(self.v.iter_mut()) {
+            let t = row[source].clone() * q.clone();
+            row[target] = row[target].clone() - t;
+        }
+    }
+
+    fn negate_row(&mut self, i : usize) {
+        for rows in [&mut self.a, &mut self.u].iter_mut() {
+            rows[i].iter_mut().for_each(|x| *x = -x.clone());
+        }
+    }
+
+    fn into_matrices(self) -> (Matrix<T>, Matrix<T>, Matrix<T>) {
+        let wrap = |m : Vec<Vec<T>>| {
+            let (rows, cols) = (m.len(), m.first().map_or(0, |r| r.len()));
+            Matrix { m, rows, cols }
+        };
+        (wrap(self.a), wrap(self.u), wrap(self.v))
+    }
+}
+
+impl<T : Integer+Signed+Clone> Matrix<T> {
+    // Smith normal form: returns (S, U, V) with U A V = S, where U and V are
+    // unimodular and S is diagonal with non-negative entries, each dividing
+    // the next
+    pub fn smith_normal_form(&self) -> (Matrix<T>, Matrix<T>, Matrix<T>) {
+        let mut t = Tracked::new(self);
+        let (rows, cols) = (self.rows, self.cols);
+
+        for d in 0..rows.min(cols) {
+            loop {
+                // bring the smallest non-zero entry left into the pivot spot
+                let mut best : Option<(usize, usize)> = None;
+                for i in d..rows {
+                    for j in d..cols {
+                        let x = &t.a[i][j];
+                        if !x.is_zero() && best.is_none_or(|(bi, bj)| x.abs() < t.a[bi][bj].abs()) {
+                            best = Some((i, j));
+                        }
+                    }
+                }
+                let (pi, pj) = match best {
+                    Some(position) => position,
+                    None => return t.into_matrices(),
+                };
+                t.swap_rows(d, pi);
+                t.swap_cols(d, pj);
+
+                // clear the pivot's column and row as far as division allows
+                let pivot = t.a[d][d].clone();
+                for i in (d + 1)..rows {
+                    let q = t.a[i][d].div_floor(&pivot);
+                    t.sub_row(i, d, &q);
+                }
+                for j in (d + 1)..cols {
+                    let q = t.a[d][j].div_floor(&pivot);
+                    t.sub_col(j, d, &q);
+                }
+
+                // any remainders are smaller than the pivot, so go round again
+                let dirty = ((d + 1)..rows).any(|i| !t.a[i][d].is_zero())
+                    || ((d + 1)..cols).any(|j| !t.a[d][j].is_zero());
+                if dirty {
+                    continue;
+                }
+
+                // the pivot must divide everything still to be processed;
+                // if not, pulling the offending row in will shrink it
+                let offender = ((d + 1)..rows)
+                    .find(|&i| ((d + 1)..cols).any(|j| !t.a[i][j].is_multiple_of(&pivot)));
+                match offender {
+                    Some(i) => t.sub_row(d, i, &-T::one()),
+                    None => break,
+                }
+            }
+
+            if t.a[d][d].is_negative() {
+                t.negate_row(d);
+            }
+        }
+
+        t.into_matrices()
+    }
+
+    // Row-style Hermite normal form: returns (H, U) with U A = H, where U is
+    // unimodular and H is in row echelon form with positive pivots and the
+    // entries above each pivot reduced into [0, pivot)
+    pub fn hermite_normal_form(&self) -> (Matrix<T>, Matrix<T>) {
+        let mut t = Tracked::new(self);
+        let mut r = 0;
+
+        for col in 0..self.cols {
+            if r == self.rows {
+                break;
+            }
+
+            // Euclid's algorithm down the column, until only row r is non-zero
+            loop {
+                let mut best : Option<usize> = None;
+                for i in r..self.rows {
+                    let x = &t.a[i][col];
+                    if !x.is_zero() && best.is_none_or(|b| x.abs() < t.a[b][col].abs()) {
+                        best = Some(i);
+                    }
+                }
+                let pivot_row = match best {
+                    Some(i) => i,
+                    None => break,
+                };
+                t.swap_rows(r, pivot_row);
+
+                let pivot = t.a[r][col].clone();
+                for i in (r + 1)..self.rows {
+                    let q = t.a[i][col].div_floor(&pivot);
+                    t.sub_row(i, r, &q);
+                }
+                if ((r + 1)..self.rows).all(|i| t.a[i][col].is_zero()) {
+                    break;
+                }
+            }
+            if t.a[r][col].is_zero() {
+                continue;
+            }
+
+            if t.a[r][col].is_negative() {
+                t.negate_row(r);
+            }
+            let pivot = t.a[r][col].clone();
+            for i in 0..r {
+                let q = t.a[i][col].div_floor(&pivot);
+                t.sub_row(i, r, &q);
+            }
+            r += 1;
+        }
+
+        let (h, u, _) = t.into_matrices();
+        (h, u)
+    }
+}
+
+impl<T : Integer+Clone> Matrix<T> {
+    // Determinant by Bareiss' fraction-free elimination, in O(n³) integer
+    // operations. Every division is exact and every stored entry is a minor
+    // of the original matrix, but the products formed just before each
+    // division are about the square of a leading minor. Bounded types such
+    // as i64 can therefore overflow even when the determinant itself fits;
+    // use BigInt entries when that is a risk.
+    pub fn determinant_bareiss(&self) -> T {
+        assert!(self.is_square());
+
+        let n = self.rows;
+        let mut a = self.m.clone();
+        let mut prev = T::one();
+        let mut negate = false;
+
+        for k in 0..n {
+            if a[k][k].is_zero() {
+                match ((k + 1)..n).find(|&i| !a[i][k].is_zero()) {
+                    Some(i) => {
+                        a.swap(k, i);
+                        negate = !negate;
+                    }
+                    None => return T::zero(),
+                }
+            }
+
+            for i in (k + 1)..n {
+                for j in (k + 1)..n {
+                    let t = a[i][j].clone() * a[k][k].clone() - a[i][k].clone() * a[k][j].clone();
+                    a[i][j] = t / prev.clone();
+                }
+            }
+            prev = a[k][k].clone();
+        }
+
+        if negate { T::zero() - prev } else { prev }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Matrix;
+
+    fn check_smith(a : &Matrix<i64>, diagonal : &[i64]) {
+        let (s, u, v) = a.smith_normal_form();
+
+        assert_eq!(&(&u * a) * &v, s);
+        assert_eq!(u.determinant().abs(), 1);
+        assert_eq!(v.determinant().abs(), 1);
+        assert!(s.is_diagonal(0));
+        let found : Vec<i64> = (0..diagonal.len()).map(|i| s[(i, i)]).collect();
+        assert_eq!(found, diagonal);
+    }
+
+    #[test]
+    fn basic_smith_normal_form() {
+        check_smith(&Matrix::from([[2,4,4],[-6,6,12],[10,-4,-16]]), &[2,6,12]);
+        check_smith(&Matrix::from([[6,4],[4,6]]), &[2,10]);
+    }
+
+    #[test]
+    fn rectangular_smith_normal_form() {
+        // needs the divisibility fix-up: 2 doesn't divide 3
+        check_smith(&Matrix::from([[2,0,0],[0,3,0]]), &[1,6]);
+        check_smith(&Matrix::from([[1,2],[2,4],[3,6]]), &[1,0]);
+    }
+
+    #[test]
+    fn basic_hermite_normal_form() {
+        let a = Matrix::from([[2i64,3,6,2],[5,6,1,6],[8,3,1,1]]);
+        let (h, u) = a.hermite_normal_form();
+
+        assert_eq!(&u * &a, h);
+        assert_eq!(u.determinant().abs(), 1);
+        assert_eq!(h, Matrix::from([[1,0,50,-11],[0,3,28,-2],[0,0,61,-13]]));
+    }
+
+    #[test]
+    fn rank_deficient_hermite_normal_form() {
+        let a = Matrix::from([[4i64,6],[6,9],[2,3]]);
+        let (h, u) = a.hermite_normal_form();
+
+        assert_eq!(&u * &a, h);
+        assert_eq!(u.determinant().abs(), 1);
+        assert_eq!(h, Matrix::from([[2,3],[0,0],[0,0]]));
+    }
+
+    #[test]
+    fn bareiss_determinant() {
+        let a = Matrix::from([[0i64,2,1,0,3,1],[1,0,0,2,1,4],[4,1,3,0,0,2],
+                              [0,1,0,1,2,0],[2,0,1,1,1,3],[1,5,2,0,1,1]]);
+        assert_eq!(a.determinant_bareiss(), a.determinant());
+
+        // entries near 10⁹ whose determinant still fits in i64, which
+        // floating point elimination couldn't get exactly
+        let big = Matrix::from([[1_000_000_007i64,999_999_937],[999_999_929,999_999_893]]);
+        assert_eq!(big.determinant_bareiss(), 1_000_000_007 * 999_999_893 - 999_999_937 * 999_999_929);
+
+        assert_eq!(Matrix::from([[1i64,2],[2,4]]).determinant_bareiss(), 0);
+        assert_eq!(Matrix::<i64>::new(0, 0).determinant_bareiss(), 1);
+    }
+}
@@ -0,0 +1,286 @@
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use num_traits::{One, Zero};
+
+use crate::Matrix;
+
+// A closed interval [lo, hi] of f64s for verified computation. Every
+// operation rounds outwards, by one ulp past the nearest-rounded bound, so
+// the result always encloses every exact result of the operands' points.
+// Matrices of intervals work with the generic add, mul and friends, and
+// Matrix::from(points).cast::<Interval>() turns a float matrix into one.
+#[derive(Clone,Copy,Debug,Default,PartialEq)]
+pub struct Interval {
+    lo : f64,
+    hi : f64,
+}
+
+impl Interval {
+    pub fn new(lo : f64, hi : f64) -> Self {
+        assert!(lo <= hi);
+        Interval { lo, hi }
+    }
+
+    // The degenerate interval [x, x]
+    pub fn point(x : f64) -> Self {
+        Interval { lo : x, hi : x }
+    }
+
+    // The whole real line, as the result of dividing by an interval
+    // containing zero
+    pub fn entire() -> Self {
+        Interval { lo : f64::NEG_INFINITY, hi : f64::INFINITY }
+    }
+
+    pub fn lo(&self) -> f64 {
+        self.lo
+    }
+
+    pub fn hi(&self) -> f64 {
+        self.hi
+    }
+
+    pub fn width(&self) -> f64 {
+        self.hi - self.lo
+    }
+
+    pub fn midpoint(&self) -> f64 {
+        self.lo / 2.0 + self.hi / 2.0
+    }
+
+    pub fn contains(&self, x : f64) -> bool {
+        self.lo <= x && x <= self.hi
+    }
+
+    // The smallest absolute value of any point, zero if the interval
+    // contains zero
+    pub fn mignitude(&self) -> f64 {
+        if self.contains(0.0) { 0.0 } else { self.lo.abs().min(self.hi.abs()) }
+    }
+
+    // A NaN bound, from ∞ - ∞, means the result is unbounded on that side
+    fn outward(lo : f64, hi : f64) -> Self {
+        let lo = if lo.is_nan() { f64::NEG_INFINITY } else { lo };
+        let hi = if hi.is_nan() { f64::INFINITY } else { hi };
+        debug_assert!(lo <= hi);
+        Interval { lo : lo.next_down(), hi : hi.next_up() }
+    }
+
+    // Hull of the four endpoint combinations under op. A NaN corner (∞/∞)
+    // says nothing about the result, so it gives up to the whole line.
+    fn corners(self, other : Interval, op : fn(f64, f64) -> f64) -> Self {
+        let c = [op(self.lo, other.lo), op(self.lo, other.hi), op(self.hi, other.lo), op(self.hi, other.hi)];
+        if c.iter().any(|x| x.is_nan()) {
+            return Interval::entire();
+        }
+        Interval::outward(c.iter().cloned().fold(f64::INFINITY, f64::min), c.iter().cloned().fold(f64::NEG_INFINITY, f64::max))
+    }
+}
+
+impl From<f64> for Interval {
+    fn from(x : f64) -> Self {
+        Interval::point(x)
+    }
+}
+
+impl Add for Interval {
+    type Output = Interval;
+
+    fn add(self, other : Interval) -> Interval {
+        Interval::outward(self.lo + other.lo, self.hi + other.hi)
+    }
+}
+
+impl Sub for Interval {
+    type Output = Interval;
+
+    fn sub(self, other : Interval) -> Interval {
+        Interval::outward(self.lo - other.hi, self.hi - other.lo)
+    }
+}
+
+impl Mul for Interval {
+    type Output = Interval;
+
+    // 0 · ±∞ counts as 0: the zero endpoint is a real point, while ∞ only
+    // stands for unboundedly large finite ones
+    fn mul(self, other : Interval) -> Interval {
+        self.corners(other, |a, b| if a == 0.0 || b == 0.0 { 0.0 } else { a * b })
+    }
+}
+
+impl Div for Interval {
+    type Output = Interval;
+
+    fn div(self, other : Interval) -> Interval {
+        if other.contains(0.0) {
+            Interval::entire()
+        } else {
+            self.corners(other, |a, b| a / b)
+        }
+    }
+}
+
+// Negation is exact
+impl Neg for Interval {
+    type Output = Interval;
+
+    fn neg(self) -> Interval {
+        Interval { lo : -self.hi, hi : -self.lo }
+    }
+}
+
+impl AddAssign for Interval {
+    fn add_assign(&mut self, other : Interval) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Interval {
+    fn sub_assign(&mut self, other : Interval) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign for Interval {
+    fn mul_assign(&mut self, other : Interval) {
+        *self = *self * other;
+    }
+}
+
+impl Zero for Interval {
+    fn zero() -> Self {
+        Interval::point(0.0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.lo == 0.0 && self.hi == 0.0
+    }
+}
+
+impl One for Interval {
+    fn one() -> Self {
+        Interval::point(1.0)
+    }
+}
+
+impl Matrix<Interval> {
+    // Interval Gaussian elimination, pivoting on the entry of largest
+    // mignitude. The result encloses the solution of every point system
+    // inside the interval A and B, or is None if elimination can't prove
+    // that all of them are nonsingular (a pivot interval contains zero).
+    // Enclosures widen with n, so this suits small, well conditioned
+    // systems best.
+    pub fn solve(&self, b : &Matrix<Interval>) -> Option<Matrix<Interval>> {
+        assert!(self.is_square() && self.rows == b.rows);
+
+        let n = self.rows;
+        let mut a = self.m.clone();
+        let mut x = b.m.clone();
+        for col in 0..n {
+            let pivot = (col..n).fold(col, |best, row| if a[row][col].mignitude() > a[best][col].mignitude() { row } else { best });
+            if a[pivot][col].mignitude() == 0.0 {
+                return None;
+            }
+            a.swap(col, pivot);
+            x.swap(col, pivot);
+
+            for row in (col + 1)..n {
+                let factor = a[row][col] / a[col][col];
+                for j in col..n {
+                    let t = factor * a[col][j];
+                    a[row][j] -= t;
+                }
+                for j in 0..b.cols {
+                    let t = factor * x[col][j];
+                    x[row][j] -= t;
+                }
+            }
+        }
+
+        for col in (0..n).rev() {
+            for j in 0..b.cols {
+                let s = ((col + 1)..n).fold(x[col][j], |acc, k| acc - a[col][k] * x[k][j]);
+                x[col][j] = s / a[col][col];
+            }
+        }
+
+        Some(Matrix { m : x, rows : n, cols : b.cols })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Interval;
+    use crate::Matrix;
+
+    #[test]
+    fn interval_operations() {
+        let (a, b) = (Interval::new(1.0, 2.0), Interval::new(-3.0, 0.5));
+        let sum = a + b;
+        assert!(sum.contains(-2.0) && sum.contains(2.5) && sum.width() < 4.5 + 1e-14);
+        assert!((a * b).contains(-6.0) && (a * b).contains(1.0) && !(a * b).contains(1.1));
+        assert_eq!(a / b, Interval::entire());
+        assert!((b / a).contains(-3.0) && (b / a).contains(0.5));
+        assert_eq!(-a, Interval::new(-2.0, -1.0));
+
+        // summing 0.1 ten times drifts below 1 in floats; the enclosure
+        // holds both that drifted value and the nearby exact sum
+        let drifted = (0..10).fold(0.0, |acc, _| acc + 0.1);
+        let total = (0..10).fold(Interval::point(0.0), |acc, _| acc + Interval::point(0.1));
+        assert!(drifted != 1.0 && total.contains(drifted) && total.contains(1.0));
+        assert!(total.width() < 1e-14);
+    }
+
+    #[test]
+    fn unbounded_intervals() {
+        let entire = Interval::entire();
+        for zero in [Interval::point(0.0) * entire, entire * Interval::point(0.0)].iter() {
+            assert!(zero.contains(0.0) && zero.width() < 1e-300);
+        }
+        assert_eq!(Interval::point(2.0) * entire, entire);
+        assert!((Interval::new(0.0, 1.0) * entire).contains(-1e300));
+        assert_eq!(entire - entire, entire);
+
+        // ∞/∞ corners can't be pinned down
+        let above = Interval::new(1.0, f64::INFINITY);
+        assert_eq!(above / above, entire);
+
+        // (1..2)/(-1..1) is the whole line, and it must not swallow the 3
+        let a = Matrix::from(vec![vec![Interval::new(1.0, 2.0) / Interval::new(-1.0, 1.0), Interval::point(1.0)]]);
+        let b = Matrix::from(vec![vec![Interval::point(0.0)],vec![Interval::point(3.0)]]);
+        let product = &a * &b;
+        assert!(product[(0, 0)].contains(3.0) && product[(0, 0)].lo() <= product[(0, 0)].hi());
+    }
+
+    #[test]
+    fn interval_matrices() {
+        let a = Matrix::from([[4.0f64,1.0,0.0],[1.0,3.0,-1.0],[0.0,-1.0,2.0]]);
+        let ai = a.cast::<Interval>();
+
+        // the generic product encloses the float one
+        let product = &ai * &ai;
+        let exact = &a * &a;
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!(product[(i, j)].contains(exact[(i, j)]));
+            }
+        }
+
+        // x = (1, -2, 3) solves a x = b exactly in integers
+        let b = Matrix::from([[2.0],[-8.0],[8.0]]).cast::<Interval>();
+        let x = ai.solve(&b).unwrap();
+        for (i, &v) in [1.0,-2.0,3.0].iter().enumerate() {
+            assert!(x[(i, 0)].contains(v) && x[(i, 0)].width() < 1e-12);
+        }
+
+        // uncertain entries give a wider, still enclosing, answer
+        let mut wide = ai.clone();
+        wide.m[0][0] = Interval::new(3.9, 4.1);
+        let x = wide.solve(&b).unwrap();
+        assert!(x[(0, 0)].contains(1.0) && x[(0, 0)].width() > 1e-3);
+
+        let singular = Matrix::from([[1.0f64,2.0],[2.0,4.0]]).cast::<Interval>();
+        assert!(singular.solve(&Matrix::from([[1.0],[1.0]]).cast::<Interval>()).is_none());
+    }
+}
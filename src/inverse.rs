@@ -0,0 +1,251 @@
+use num_traits::Float;
+
+use crate::conditioning::inverse_power_of_two;
+use crate::Matrix;
+
+// Floating point only: integer division would truncate, and exact types
+// have inverse_exact
+impl<T : Float+Default> Matrix<T> {
+    // Inverse of a square matrix, or None if it is singular or numerically
+    // so. Each row is first scaled by a power of two to bring its largest
+    // entry near 1, which is exact and makes the answer independent of how
+    // the rows are scaled; A⁻¹ is then (DA)⁻¹D. Matrices up to 4×4 invert
+    // DA by closed-form adj/det, anything larger by Gauss-Jordan
+    // elimination with partial pivoting, and either way the result is
+    // rejected when ‖DA‖∞‖(DA)⁻¹‖∞, the condition number of DA, is at
+    // least 1 / (n·ε).
+    pub fn inverse(&self) -> Option<Matrix<T>> {
+        assert!(self.is_square());
+
+        let n = self.rows;
+        if n == 0 {
+            return Some(self.clone());
+        }
+
+        let mut scale = Vec::with_capacity(n);
+        for row in &self.m {
+            let max = row.iter().fold(T::zero(), |m, x| m.max(x.abs()));
+            if max == T::zero() {
+                return None;
+            }
+            scale.push(inverse_power_of_two(max));
+        }
+        let scaled = Matrix::from_fn(n, n, |i, j| self.m[i][j] * scale[i]);
+
+        let inv = if n <= 4 { scaled.small_inverse()? } else { scaled.gauss_jordan_inverse()? };
+        let condition = norm_inf(&scaled) * norm_inf(&inv);
+        if condition.is_nan() || condition * T::from(n).unwrap() * T::epsilon() >= T::one() {
+            return None;
+        }
+
+        Some(Matrix::from_fn(n, n, |i, j| inv.m[i][j] * scale[j]))
+    }
+
+    fn small_inverse(&self) -> Option<Matrix<T>> {
+        let zero = T::zero();
+        let a = |i : usize, j : usize| self.m[i][j];
+
+        let (adj, det) = match self.rows {
+            1 => (vec![vec![T::one()]], a(0,0)),
+            2 => {
+                let adj = vec![
+                    vec![a(1,1), zero - a(0,1)],
+                    vec![zero - a(1,0), a(0,0)],
+                ];
+                (adj, self.determinant())
+            }
+            3 => {
+                let adj = vec![
+                    vec![a(1,1) * a(2,2) - a(1,2) * a(2,1),
+                         a(0,2) * a(2,1) - a(0,1) * a(2,2),
+                         a(0,1) * a(1,2) - a(0,2) * a(1,1)],
+                    vec![a(1,2) * a(2,0) - a(1,0) * a(2,2),
+                         a(0,0) * a(2,2) - a(0,2) * a(2,0),
+                         a(0,2) * a(1,0) - a(0,0) * a(1,2)],
+                    vec![a(1,0) * a(2,1) - a(1,1) * a(2,0),
+                         a(0,1) * a(2,0) - a(0,0) * a(2,1),
+                         a(0,0) * a(1,1) - a(0,1) * a(1,0)],
+                ];
+                (adj, self.determinant())
+            }
+            _ => {
+                let [s0, s1, s2, s3, s4, s5] = self.upper_minors();
+                let [c0, c1, c2, c3, c4, c5] = self.lower_minors();
+                let det = s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0;
+
+                let adj = vec![
+                    vec![a(1,1) * c5 - a(1,2) * c4 + a(1,3) * c3,
+                         a(0,2) * c4 - a(0,1) * c5 - a(0,3) * c3,
+                         a(3,1) * s5 - a(3,2) * s4 + a(3,3) * s3,
+                         a(2,2) * s4 - a(2,1) * s5 - a(2,3) * s3],
+                    vec![a(1,2) * c2 - a(1,0) * c5 - a(1,3) * c1,
+                         a(0,0) * c5 - a(0,2) * c2 + a(0,3) * c1,
+                         a(3,2) * s2 - a(3,0) * s5 - a(3,3) * s1,
+                         a(2,0) * s5 - a(2,2) * s2 + a(2,3) * s1],
+                    vec![a(1,0) * c4 - a(1,1) * c2 + a(1,3) * c0,
+                         a(0,1) * c2 - a(0,0) * c4 - a(0,3) * c0,
+                         a(3,0) * s4 - a(3,1) * s2 + a(3,3) * s0,
+                         a(2,1) * s2 - a(2,0) * s4 - a(2,3) * s0],
+                    vec![a(1,1) * c1 - a(1,0) * c3 - a(1,2) * c0,
+                         a(0,0) * c3 - a(0,1) * c1 + a(0,2) * c0,
+                         a(3,1) * s1 - a(3,0) * s3 - a(3,2) * s0,
+                         a(2,0) * s3 - a(2,1) * s1 + a(2,2) * s0],
+                ];
+                (adj, det)
+            }
+        };
+
+        if det == zero {
+            return None;
+        }
+
+        let m = adj.into_iter()
+            .map(|row| row.into_iter().map(|x| x / det).collect())
+            .collect();
+        Some(Matrix { m, rows : self.rows, cols : self.cols })
+    }
+
+    fn gauss_jordan_inverse(&self) -> Option<Matrix<T>> {
+        let n = self.rows;
+        let zero = T::zero();
+
+        let mut a = self.m.clone();
+        let mut inv = Matrix::<T>::identity(n).m;
+
+        for col in 0..n {
+            // pick the largest remaining entry in this column as the pivot
+            let mut pivot = col;
+            for row in (col + 1)..n {
+                if a[row][col].abs() > a[pivot][col].abs() {
+                    pivot = row;
+                }
+            }
+            if a[pivot][col] == zero {
+                return None;
+            }
+            a.swap(col, pivot);
+            inv.swap(col, pivot);
+
+            let p = a[col][col];
+            for j in 0..n {
+                a[col][j] = a[col][j] / p;
+                inv[col][j] = inv[col][j] / p;
+            }
+
+            for row in 0..n {
+                if row == col || a[row][col] == zero {
+                    continue;
+                }
+                let factor = a[row][col];
+                for j in 0..n {
+                    a[row][j] = a[row][j] - factor * a[col][j];
+                    inv[row][j] = inv[row][j] - factor * inv[col][j];
+                }
+            }
+        }
+
+        Some(Matrix { m : inv, rows : n, cols : n })
+    }
+}
+
+// Maximum absolute row sum, the operator norm induced by the ∞-norm
+fn norm_inf<T : Float>(a : &Matrix<T>) -> T {
+    a.m.iter().map(|row| row.iter().fold(T::zero(), |acc, x| acc + x.abs())).fold(T::zero(), T::max)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Matrix;
+
+    fn assert_inverse(a : &Matrix<f64>) {
+        let inv = a.inverse().unwrap();
+        assert!((a * &inv).is_identity(1e-10));
+        assert!((&inv * a).is_identity(1e-10));
+    }
+
+    #[test]
+    fn small_matrix_inverse() {
+        assert_eq!(Matrix::from([[4.0]]).inverse(), Some(Matrix::from([[0.25]])));
+        assert_eq!(Matrix::from([[4.0,7.0],[2.0,6.0]]).inverse(),
+                   Some(Matrix::from([[0.6,-0.7],[-0.2,0.4]])));
+
+        assert_inverse(&Matrix::from([[2.0,0.0,1.0],[1.0,3.0,2.0],[1.0,1.0,2.0]]));
+        assert_inverse(&Matrix::from([[2.0,-1.0,0.0,3.0],[1.0,1.0,4.0,0.0],
+                                      [0.0,2.0,-3.0,1.0],[5.0,0.0,1.0,2.0]]));
+    }
+
+    #[test]
+    fn large_matrix_inverse() {
+        // zero on the leading diagonal forces a row swap
+        let a = Matrix::from([[0.0,2.0,1.0,0.0,3.0],[1.0,0.0,0.0,2.0,1.0],[4.0,1.0,3.0,0.0,0.0],
+                              [0.0,1.0,0.0,1.0,2.0],[2.0,0.0,1.0,1.0,1.0]]);
+        assert_inverse(&a);
+    }
+
+    #[test]
+    fn singular_matrix_inverse() {
+        assert_eq!(Matrix::from([[1.0,2.0],[2.0,4.0]]).inverse(), None);
+
+        let mut a = Matrix::<f64>::identity(6);
+        a.m[5][5] = 0.0;
+        assert_eq!(a.inverse(), None);
+
+        // singular up to rounding: exact comparisons with zero would accept these
+        assert_eq!(Matrix::from([[1.0,2.0],[2.0,4.0 + 1e-15]]).inverse(), None);
+        let mut b = Matrix::<f64>::identity(6);
+        b.m[5][4] = 1.0;
+        b.m[4][5] = 1.0;
+        b.m[5][5] = 1.0 + 1e-15;
+        assert_eq!(b.inverse(), None);
+
+        // the same nearly dependent pair of rows at either size
+        for &n in [4, 5].iter() {
+            let mut c = Matrix::<f64>::identity(n);
+            c.m[0][1] = 1.0;
+            c.m[1][0] = 1.0;
+            c.m[1][1] = 1.0 + 1e-15;
+            assert_eq!(c.inverse(), None);
+        }
+    }
+
+    #[test]
+    fn scaled_matrix_inverse() {
+        // the answer doesn't depend on how the rows are scaled, so it is the
+        // same for closed forms (n = 4) and elimination (n = 5)
+        for &n in [4, 5].iter() {
+            for &s in [1e-100, 1e100].iter() {
+                let a = Matrix::from_fn(n, n, |i, j| if i == j { s } else { 0.0 });
+                let expected = Matrix::from_fn(n, n, |i, j| if i == j { 1.0 / s } else { 0.0 });
+                assert_eq!(a.inverse(), Some(expected));
+
+                // a well conditioned matrix scaled uniformly
+                let b = Matrix::from_fn(n, n, |i, j| s * if i == j { 4.0 } else { 1.0 / (1 + i + j) as f64 });
+                let inv = b.inverse().unwrap();
+                assert!((&b * &inv).is_identity(1e-12));
+            }
+
+            let d = Matrix::from_fn(n, n, |i, j| if i != j { 0.0 } else if i == 0 { 1e-20 } else { 1.0 });
+            let inv = d.inverse().unwrap();
+            assert_eq!(inv[(0, 0)], 1e20);
+
+            // rows scaled far apart; A⁻¹A = (DA)⁻¹(DA) is the product that
+            // stays close to I, since AA⁻¹ multiplies its errors by the ratios
+            let r = Matrix::from_fn(n, n, |i, j| 10f64.powi(40 * i as i32 - 80) * if i == j { 4.0 } else { 1.0 });
+            let inv = r.inverse().unwrap();
+            assert!((&inv * &r).is_identity(1e-12));
+        }
+    }
+
+    #[test]
+    fn small_determinant_matches_expansion() {
+        let a = Matrix::from([[2,-1,0,3],[1,1,4,0],[0,2,-3,1],[5,0,1,2]]);
+        let mut total = 0;
+        for j in 0..4 {
+            let term = a.m[0][j] * a.minor(0, j);
+            total += if j % 2 == 0 { term } else { -term };
+        }
+
+        assert_eq!(a.determinant(), total);
+        assert_eq!(a.determinant(), 126);
+    }
+}
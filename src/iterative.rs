@@ -0,0 +1,450 @@
+use std::ops::{AddAssign, Mul};
+
+use num_traits::Float;
+
+use crate::{BandedMatrix, IdentityPreconditioner, Matrix, Preconditioner, SparseMatrix};
+
+// Anything which can compute the product Ax for a vector x, so that the
+// iterative solvers work with dense, banded and sparse matrices alike
+pub trait LinearOperator<T> {
+    fn rows(&self) -> usize;
+    fn cols(&self) -> usize;
+    fn apply(&self, x : &[T]) -> Vec<T>;
+}
+
+impl<T : AddAssign+Clone+Default+Mul<Output=T>> LinearOperator<T> for Matrix<T> {
+    fn rows(&self) -> usize {
+        self.rows
+    }
+
+    fn cols(&self) -> usize {
+        self.cols
+    }
+
+    fn apply(&self, x : &[T]) -> Vec<T> {
+        self.mul_vec(x)
+    }
+}
+
+impl<T : AddAssign+Clone+Default+Mul<Output=T>> LinearOperator<T> for SparseMatrix<T> {
+    fn rows(&self) -> usize {
+        SparseMatrix::rows(self)
+    }
+
+    fn cols(&self) -> usize {
+        SparseMatrix::cols(self)
+    }
+
+    fn apply(&self, x : &[T]) -> Vec<T> {
+        self.mul_vec(x)
+    }
+}
+
+impl<T : AddAssign+Clone+Default+Mul<Output=T>> LinearOperator<T> for BandedMatrix<T> {
+    fn rows(&self) -> usize {
+        self.size()
+    }
+
+    fn cols(&self) -> usize {
+        self.size()
+    }
+
+    fn apply(&self, x : &[T]) -> Vec<T> {
+        self.mul_vec(x)
+    }
+}
+
+// Wraps a closure computing Ax as a LinearOperator, for matrix-free solves
+pub struct FnOperator<F> {
+    rows : usize,
+    cols : usize,
+    f : F,
+}
+
+impl<F> FnOperator<F> {
+    pub fn new(rows : usize, cols : usize, f : F) -> Self {
+        FnOperator { rows, cols, f }
+    }
+}
+
+impl<T, F : Fn(&[T]) -> Vec<T>> LinearOperator<T> for FnOperator<F> {
+    fn rows(&self) -> usize {
+        self.rows
+    }
+
+    fn cols(&self) -> usize {
+        self.cols
+    }
+
+    fn apply(&self, x : &[T]) -> Vec<T> {
+        let y = (self.f)(x);
+        assert!(y.len() == self.rows);
+        y
+    }
+}
+
+// Operators which can also hand out the stored entries of each row, as the
+// stationary methods below need to separate out the diagonal
+pub trait RowEntries<T> : LinearOperator<T> {
+    fn for_each_in_row<F : FnMut(usize, &T)>(&self, i : usize, f : F);
+}
+
+impl<T : AddAssign+Clone+Default+Mul<Output=T>> RowEntries<T> for Matrix<T> {
+    fn for_each_in_row<F : FnMut(usize, &T)>(&self, i : usize, mut f : F) {
+        for (j, x) in self.m[i].iter().enumerate() {
+            f(j, x);
+        }
+    }
+}
+
+impl<T : AddAssign+Clone+Default+Mul<Output=T>> RowEntries<T> for SparseMatrix<T> {
+    fn for_each_in_row<F : FnMut(usize, &T)>(&self, i : usize, mut f : F) {
+        for (j, x) in self.row_entries(i) {
+            f(j, x);
+        }
+    }
+}
+
+impl<T : AddAssign+Clone+Default+Mul<Output=T>> RowEntries<T> for BandedMatrix<T> {
+    fn for_each_in_row<F : FnMut(usize, &T)>(&self, i : usize, mut f : F) {
+        for j in self.band_cols(i) {
+            f(j, &self[(i, j)]);
+        }
+    }
+}
+
+// Result of an iterative solve. residual is the 2-norm of b - Ax at the
+// returned x (as tracked by the iteration itself), and converged says
+// whether it got below the requested tolerance within the iteration limit.
+#[derive(Clone,Debug,PartialEq)]
+pub struct IterativeSolution<T> {
+    pub x : Vec<T>,
+    pub iterations : usize,
+    pub residual : T,
+    pub converged : bool,
+}
+
+pub(crate) fn dot<T : Float>(x : &[T], y : &[T]) -> T {
+    x.iter().zip(y.iter()).fold(T::zero(), |acc, (&a, &b)| acc + a * b)
+}
+
+pub(crate) fn norm<T : Float>(x : &[T]) -> T {
+    dot(x, x).sqrt()
+}
+
+// The residual b - Ax
+pub(crate) fn residual<T : Float, A : LinearOperator<T>>(a : &A, b : &[T], x : &[T]) -> Vec<T> {
+    a.apply(x).iter().zip(b.iter()).map(|(&ax, &bi)| bi - ax).collect()
+}
+
+// Conjugate gradient for symmetric positive-definite systems Ax = b,
+// starting from x0 and stopping once ||b - Ax|| <= tol ||b||
+pub fn cg<T : Float, A : LinearOperator<T>>(a : &A, b : &[T], x0 : &[T], tol : T, max_iter : usize) -> IterativeSolution<T> {
+    cg_preconditioned(a, &IdentityPreconditioner, b, x0, tol, max_iter)
+}
+
+// Conjugate gradient with a symmetric positive-definite preconditioner M,
+// which should approximate A⁻¹
+pub fn cg_preconditioned<T, A, M>(a : &A, m : &M, b : &[T], x0 : &[T], tol : T, max_iter : usize) -> IterativeSolution<T>
+    where T : Float, A : LinearOperator<T>, M : Preconditioner<T> {
+    assert!(a.rows() == a.cols());
+    assert!(a.rows() == b.len() && b.len() == x0.len());
+
+    let threshold = tol * norm(b);
+    let mut x = x0.to_vec();
+    let mut r = residual(a, b, &x);
+    let mut z = m.apply(&r);
+    let mut p = z.clone();
+    let mut rz = dot(&r, &z);
+    let mut iterations = 0;
+
+    while norm(&r) > threshold && iterations < max_iter {
+        let ap = a.apply(&p);
+        let alpha = rz / dot(&p, &ap);
+
+        for i in 0..x.len() {
+            x[i] = x[i] + alpha * p[i];
+            r[i] = r[i] - alpha * ap[i];
+        }
+
+        z = m.apply(&r);
+        let rz_next = dot(&r, &z);
+        let beta = rz_next / rz;
+        for i in 0..p.len() {
+            p[i] = z[i] + beta * p[i];
+        }
+
+        rz = rz_next;
+        iterations += 1;
+    }
+
+    let residual = norm(&r);
+    IterativeSolution { x, iterations, residual, converged : residual <= threshold }
+}
+
+// Splits row i of A into its diagonal entry and the sum of a_ij x_j over the
+// off-diagonal entries
+fn split_row<T : Float, A : RowEntries<T>>(a : &A, i : usize, x : &[T]) -> (T, T) {
+    let mut diag = T::zero();
+    let mut off = T::zero();
+    a.for_each_in_row(i, |j, &v| {
+        if j == i {
+            diag = v;
+        } else {
+            off = off + v * x[j];
+        }
+    });
+    assert!(diag != T::zero(), "stationary methods need a non-zero diagonal");
+    (diag, off)
+}
+
+// Runs one sweep at a time until ||b - Ax|| <= tol ||b||
+fn stationary<T, A, F>(a : &A, b : &[T], x0 : &[T], tol : T, max_iter : usize, mut sweep : F) -> IterativeSolution<T>
+    where T : Float, A : RowEntries<T>, F : FnMut(&mut Vec<T>) {
+    assert!(a.rows() == a.cols());
+    assert!(a.rows() == b.len() && b.len() == x0.len());
+
+    let threshold = tol * norm(b);
+    let mut x = x0.to_vec();
+    let mut res = norm(&residual(a, b, &x));
+    let mut iterations = 0;
+
+    while res > threshold && iterations < max_iter {
+        sweep(&mut x);
+        res = norm(&residual(a, b, &x));
+        iterations += 1;
+    }
+
+    IterativeSolution { x, iterations, residual : res, converged : res <= threshold }
+}
+
+// Weighted Jacobi iteration; omega = 1 gives the classic method. Converges
+// for strictly diagonally dominant systems.
+pub fn jacobi<T, A>(a : &A, b : &[T], x0 : &[T], omega : T, tol : T, max_iter : usize) -> IterativeSolution<T>
+    where T : Float, A : RowEntries<T> {
+    stationary(a, b, x0, tol, max_iter, |x| {
+        let previous = x.clone();
+        for i in 0..x.len() {
+            let (diag, off) = split_row(a, i, &previous);
+            x[i] = (T::one() - omega) * previous[i] + omega * (b[i] - off) / diag;
+        }
+    })
+}
+
+// Successive over-relaxation, updating x in place during each sweep.
+// Needs 0 < omega < 2; omega = 1 is Gauss-Seidel.
+pub fn sor<T, A>(a : &A, b : &[T], x0 : &[T], omega : T, tol : T, max_iter : usize) -> IterativeSolution<T>
+    where T : Float, A : RowEntries<T> {
+    stationary(a, b, x0, tol, max_iter, |x| {
+        for i in 0..x.len() {
+            let (diag, off) = split_row(a, i, x);
+            x[i] = (T::one() - omega) * x[i] + omega * (b[i] - off) / diag;
+        }
+    })
+}
+
+pub fn gauss_seidel<T, A>(a : &A, b : &[T], x0 : &[T], tol : T, max_iter : usize) -> IterativeSolution<T>
+    where T : Float, A : RowEntries<T> {
+    sor(a, b, x0, T::one(), tol, max_iter)
+}
+
+// Restarted GMRES(restart) for general (nonsymmetric) systems Ax = b,
+// stopping once ||b - Ax|| <= tol ||b||. max_iter bounds the total number of
+// products with A across all restarts.
+pub fn gmres<T, A>(a : &A, b : &[T], x0 : &[T], restart : usize, tol : T, max_iter : usize) -> IterativeSolution<T>
+    where T : Float, A : LinearOperator<T> {
+    gmres_preconditioned(a, &IdentityPreconditioner, b, x0, restart, tol, max_iter)
+}
+
+// GMRES with right preconditioning, i.e. solving AM⁻¹u = b with x = M⁻¹u, so
+// the residual being minimised is still the true residual of Ax = b
+pub fn gmres_preconditioned<T, A, M>(a : &A, m : &M, b : &[T], x0 : &[T], restart : usize, tol : T, max_iter : usize) -> IterativeSolution<T>
+    where T : Float, A : LinearOperator<T>, M : Preconditioner<T> {
+    assert!(a.rows() == a.cols());
+    assert!(a.rows() == b.len() && b.len() == x0.len());
+    assert!(restart > 0);
+
+    let n = b.len();
+    let threshold = tol * norm(b);
+    let mut x = x0.to_vec();
+    let mut iterations = 0;
+
+    loop {
+        let r = residual(a, b, &x);
+        let beta = norm(&r);
+        if beta <= threshold || iterations >= max_iter {
+            break;
+        }
+
+        // Arnoldi basis, the Hessenberg matrix (kept triangular by Givens
+        // rotations as we go) and the rotated right-hand side
+        let mut v = vec![r.iter().map(|&ri| ri / beta).collect::<Vec<T>>()];
+        let mut h = vec![vec![T::zero() ; restart] ; restart + 1];
+        let mut rotations : Vec<(T, T)> = Vec::with_capacity(restart);
+        let mut g = vec![T::zero() ; restart + 1];
+        g[0] = beta;
+
+        let mut k = 0;
+        while k < restart && iterations < max_iter {
+            let mut w = a.apply(&m.apply(&v[k]));
+            iterations += 1;
+
+            // modified Gram-Schmidt against the existing basis
+            for i in 0..=k {
+                h[i][k] = dot(&w, &v[i]);
+                for l in 0..n {
+                    w[l] = w[l] - h[i][k] * v[i][l];
+                }
+            }
+            h[k + 1][k] = norm(&w);
+            let breakdown = h[k + 1][k] == T::zero();
+            if !breakdown {
+                v.push(w.iter().map(|&wl| wl / h[k + 1][k]).collect());
+            }
+
+            for (i, &(c, s)) in rotations.iter().enumerate() {
+                let (p, q) = (h[i][k], h[i + 1][k]);
+                h[i][k] = c * p + s * q;
+                h[i + 1][k] = c * q - s * p;
+            }
+
+            let rho = h[k][k].hypot(h[k + 1][k]);
+            let (c, s) = if rho == T::zero() { (T::one(), T::zero()) } else { (h[k][k] / rho, h[k + 1][k] / rho) };
+            h[k][k] = rho;
+            h[k + 1][k] = T::zero();
+            g[k + 1] = -s * g[k];
+            g[k] = c * g[k];
+            rotations.push((c, s));
+
+            k += 1;
+            if breakdown || g[k].abs() <= threshold {
+                break;
+            }
+        }
+
+        // back substitution for the least-squares coefficients
+        let mut y = vec![T::zero() ; k];
+        for i in (0..k).rev() {
+            let mut total = g[i];
+            for j in (i + 1)..k {
+                total = total - h[i][j] * y[j];
+            }
+            y[i] = total / h[i][i];
+        }
+        let mut u = vec![T::zero() ; n];
+        for (j, &yj) in y.iter().enumerate() {
+            for l in 0..n {
+                u[l] = u[l] + yj * v[j][l];
+            }
+        }
+        for (xl, dl) in x.iter_mut().zip(m.apply(&u)) {
+            *xl = *xl + dl;
+        }
+    }
+
+    let res = norm(&residual(a, b, &x));
+    IterativeSolution { x, iterations, residual : res, converged : res <= threshold }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cg, gauss_seidel, gmres, jacobi, sor, FnOperator};
+    use crate::{Matrix, SparseMatrix};
+
+    fn poisson(n : usize) -> Matrix<f64> {
+        let mut a = Matrix::new(n, n);
+        for i in 0..n {
+            a.m[i][i] = 2.0;
+            if i > 0 {
+                a.m[i][i - 1] = -1.0;
+                a.m[i - 1][i] = -1.0;
+            }
+        }
+        a
+    }
+
+    #[test]
+    fn cg_dense() {
+        let a = Matrix::from([[4.0f64,1.0],[1.0,3.0]]);
+        let solution = cg(&a, &[1.0,2.0], &[0.0,0.0], 1e-12, 10);
+
+        assert!(solution.converged);
+        // CG terminates in at most n steps in exact arithmetic
+        assert!(solution.iterations <= 2);
+        assert!((solution.x[0] - 1.0 / 11.0).abs() < 1e-12);
+        assert!((solution.x[1] - 7.0 / 11.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn cg_sparse() {
+        let a = SparseMatrix::from_dense(&poisson(50));
+        let b = vec![1.0 ; 50];
+        let solution = cg(&a, &b, &vec![0.0 ; 50], 1e-10, 200);
+
+        assert!(solution.converged);
+        assert!(solution.residual <= 1e-10 * 50f64.sqrt());
+        let r = a.mul_vec(&solution.x);
+        assert!(r.iter().all(|&v| (v - 1.0).abs() < 1e-8));
+    }
+
+    #[test]
+    fn cg_reports_non_convergence() {
+        let a = poisson(50);
+        let solution = cg(&a, &vec![1.0 ; 50], &vec![0.0 ; 50], 1e-12, 3);
+
+        assert!(!solution.converged);
+        assert_eq!(solution.iterations, 3);
+    }
+
+    #[test]
+    fn stationary_solvers() {
+        let a = Matrix::from([[10.0,-1.0,2.0,0.0],[-1.0,11.0,-1.0,3.0],
+                              [2.0,-1.0,10.0,-1.0],[0.0,3.0,-1.0,8.0]]);
+        let b = [6.0,25.0,-11.0,15.0];
+        let x0 = [0.0 ; 4];
+        let expected = [1.0f64,2.0,-1.0,1.0];
+
+        let j = jacobi(&a, &b, &x0, 1.0, 1e-10, 200);
+        let gs = gauss_seidel(&a, &b, &x0, 1e-10, 200);
+        let s = sor(&SparseMatrix::from_dense(&a), &b, &x0, 1.1, 1e-10, 200);
+
+        for solution in [&j, &gs, &s].iter() {
+            assert!(solution.converged);
+            for i in 0..4 {
+                assert!((solution.x[i] - expected[i]).abs() < 1e-8);
+            }
+        }
+        // Gauss-Seidel uses updated values straight away so needs fewer sweeps
+        assert!(gs.iterations < j.iterations);
+    }
+
+    #[test]
+    fn gmres_nonsymmetric() {
+        let a = Matrix::from([[4.0,1.0,0.0,2.0],[-1.0,3.0,1.0,0.0],
+                              [0.0,2.0,5.0,-1.0],[1.0,0.0,-2.0,6.0]]);
+        let expected = [1.0f64,-1.0,2.0,0.5];
+        let b = a.mul_vec(&expected);
+
+        // a short restart length still gets there
+        for &restart in [4, 2].iter() {
+            let solution = gmres(&a, &b, &[0.0 ; 4], restart, 1e-12, 100);
+            assert!(solution.converged);
+            for i in 0..4 {
+                assert!((solution.x[i] - expected[i]).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn gmres_matrix_free() {
+        // x -> (1 + i) x_i + x_{i+1}, an upper bidiagonal operator
+        let op = FnOperator::new(20, 20, |x : &[f64]| {
+            (0..x.len()).map(|i| (1.0 + i as f64) * x[i] + x.get(i + 1).cloned().unwrap_or(0.0)).collect()
+        });
+        let b = vec![1.0 ; 20];
+        let solution = gmres(&op, &b, &[0.0 ; 20], 20, 1e-10, 100);
+
+        assert!(solution.converged);
+        assert!(solution.residual <= 1e-10 * 20f64.sqrt());
+    }
+}
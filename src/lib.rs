@@ -1,4 +1,129 @@
-use std::ops::{Index, Add, AddAssign, Mul};
+// index loops read far more naturally than iterator chains in the numeric kernels
+#![allow(clippy::needless_range_loop)]
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::{Index, IndexMut, Add, AddAssign, Div, Mul, MulAssign, Sub, SubAssign};
+
+use num_traits::One;
+
+pub use banded::BandedMatrix;
+pub use batch::matmul_batch;
+#[cfg(feature = "rayon")]
+pub use batch::matmul_batch_par;
+pub use bitmatrix::BitMatrix;
+pub use builder::MatrixBuilder;
+#[cfg(feature = "quickcheck")]
+pub use arbitrary::{Conformable, SameShape, Square};
+#[cfg(feature = "num-complex")]
+pub use complex::HermitianEigen;
+pub use conditioning::Equilibration;
+pub use echelon::{Rref, RowOperation};
+pub use eigen::SymmetricEigen;
+pub use einsum::einsum;
+pub use elementwise::MatrixFloatExt;
+pub use expr::{Difference, Entries, Expr, MatrixRef, Negated, Product, Scaled, Sum};
+pub use image::{Padding, PoolKind, RaggedEdges};
+pub use interval::Interval;
+pub use iterative::{
+    cg, cg_preconditioned, gauss_seidel, gmres, gmres_preconditioned, jacobi, sor,
+    FnOperator, IterativeSolution, LinearOperator, RowEntries,
+};
+pub use lu::Lu;
+pub use markov::pagerank;
+pub use pca::Pca;
+pub use preconditioner::{IdentityPreconditioner, Ilu0, JacobiPreconditioner, Preconditioner};
+pub use qr::PivotedQr;
+pub use schur::Schur;
+pub use semiring::{Arithmetic, Boolean, MaxTimes, MinPlus, Semiring};
+pub use sparse::{CooBuilder, SparseMatrix};
+pub use svd::Svd;
+pub use tensor::{MatrixView, Tensor3};
+pub use transform::{ClipSpace, DepthRange, Handedness};
+
+mod banded;
+mod batch;
+mod bitmatrix;
+mod broadcast;
+mod builder;
+mod cast;
+mod conditioning;
+mod decomposition;
+mod determinant;
+mod echelon;
+mod eigen;
+mod einsum;
+mod elementwise;
+mod expr;
+mod functions;
+mod graph;
+mod hessenberg;
+mod image;
+mod integer;
+mod interval;
+mod inverse;
+mod iterative;
+mod lu;
+mod markov;
+mod mixed_precision;
+mod modular;
+mod orthogonal;
+mod pca;
+mod polynomial;
+mod preconditioner;
+mod qr;
+mod rotation;
+mod rows;
+mod saturating;
+mod schur;
+mod semiring;
+mod solve;
+mod sparse;
+mod svd;
+mod tensor;
+mod transform;
+mod transpose;
+
+#[cfg(feature = "approx")]
+mod approx_traits;
+#[cfg(feature = "quickcheck")]
+mod arbitrary;
+#[cfg(feature = "num-bigint")]
+mod bigint;
+#[cfg(feature = "num-complex")]
+mod complex;
+#[cfg(feature = "half")]
+mod half_precision;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_convert;
+#[cfg(feature = "ndarray")]
+mod ndarray_convert;
+
+#[derive(Clone,Debug,PartialEq,Eq)]
+pub enum MatrixError {
+    // a row didn't have the same length as the first row
+    RaggedRows { row : usize, expected : usize, found : usize },
+    // a column didn't have the same length as the others
+    RaggedColumns { col : usize, expected : usize, found : usize },
+    // an einsum specification that couldn't be parsed or didn't fit the operands
+    InvalidSubscripts(String),
+}
+
+impl fmt::Display for MatrixError {
+    fn fmt(&self, f : &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MatrixError::RaggedRows { row, expected, found } => {
+                write!(f, "row {} has length {} but expected {}", row, found, expected)
+            }
+            MatrixError::RaggedColumns { col, expected, found } => {
+                write!(f, "column {} has length {} but expected {}", col, found, expected)
+            }
+            MatrixError::InvalidSubscripts(reason) => write!(f, "invalid einsum subscripts: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for MatrixError {}
 
 #[derive(Clone,Debug,PartialEq,Eq)]
 pub struct Matrix<T> {
@@ -16,25 +141,6 @@ impl<T : Clone+Default> Matrix<T> {
         }
     }
     
-    pub fn transpose(&mut self) {
-        // create a new matrix in memory
-        let mut tmp = Vec::new();
-        for i in 0..self.cols {
-            let mut row = Vec::new();
-            for j in 0..self.rows {
-                row.push(self.m[j][i].clone());
-            }
-            tmp.push(row);
-        }
-
-        
-        self.m = tmp;
-        // swap row <-> column count
-        let c = self.cols;
-        self.cols = self.rows;
-        self.rows = c;
-    }
-
     // Makes a <copy> of a range of rows of a matrix
     pub fn slice<S>(&self, range : S) -> Matrix<T>
         where S : IntoIterator<Item=usize> {
@@ -44,24 +150,463 @@ impl<T : Clone+Default> Matrix<T> {
         }
         Matrix::from(tmp)
     }
+
+    // Copy keeping entries on or below the k-th diagonal (k = 0 is the main
+    // diagonal, k > 0 above it, k < 0 below it) and zeroing the rest
+    pub fn lower_triangular(&self, k : isize) -> Matrix<T> {
+        self.masked(|i, j| j as isize - i as isize <= k)
+    }
+
+    // Copy keeping entries on or above the k-th diagonal and zeroing the rest
+    pub fn upper_triangular(&self, k : isize) -> Matrix<T> {
+        self.masked(|i, j| j as isize - i as isize >= k)
+    }
+
+    fn masked<F : Fn(usize, usize) -> bool>(&self, keep : F) -> Matrix<T> {
+        Matrix::from_fn(self.rows, self.cols, |i, j| {
+            if keep(i, j) { self.m[i][j].clone() } else { T::default() }
+        })
+    }
 }
 
-impl<T : Clone+Default> From<Vec<Vec<T>>> for Matrix<T> {
-    fn from(other: Vec<Vec<T>>) -> Self {
-        if other.len() == 0 {
-            return Matrix::new(0,0);
+// Checks |a - b| <= tol, written so that it also works for unsigned types
+fn within_tolerance<T : Clone+PartialOrd+Sub<Output=T>>(a : &T, b : &T, tol : &T) -> bool {
+    let diff = if a >= b { a.clone() - b.clone() } else { b.clone() - a.clone() };
+    &diff <= tol
+}
+
+impl<T> Matrix<T> {
+    // Builds a matrix entry by entry, without needing T : Default
+    pub fn from_fn<F>(rows : usize, cols : usize, mut f : F) -> Self
+        where F : FnMut(usize, usize) -> T {
+        let m = (0..rows).map(|i| (0..cols).map(|j| f(i, j)).collect()).collect();
+        Matrix { m, rows, cols }
+    }
+
+    // Collects each row straight into place, e.g. from lines of parsed input.
+    // Every row must have the same length as the first.
+    pub fn from_rows<I, R>(rows : I) -> Result<Self, MatrixError>
+        where I : IntoIterator<Item=R>, R : IntoIterator<Item=T> {
+        let m : Vec<Vec<T>> = rows.into_iter().map(|row| row.into_iter().collect()).collect();
+        let cols = m.first().map_or(0, |row| row.len());
+
+        for (i, row) in m.iter().enumerate() {
+            if row.len() != cols {
+                return Err(MatrixError::RaggedRows { row : i, expected : cols, found : row.len() });
+            }
+        }
+
+        Ok(Matrix { rows : m.len(), m, cols })
+    }
+
+    // rows × cols matrix filled from entries in row-major order, the inverse
+    // of into_row_major_vec. Panics unless there are exactly rows * cols.
+    pub fn from_row_major<I : IntoIterator<Item=T>>(rows : usize, cols : usize, entries : I) -> Self {
+        let mut entries = entries.into_iter();
+        let m : Vec<Vec<T>> = (0..rows).map(|_| entries.by_ref().take(cols).collect()).collect();
+
+        assert!(m.iter().all(|row| row.len() == cols));
+        assert!(entries.next().is_none());
+        Matrix { m, rows, cols }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    // A vector is any matrix with a single row or a single column
+    pub fn is_vector(&self) -> bool {
+        self.rows == 1 || self.cols == 1
+    }
+
+    // Walks the entries of a vector in order, regardless of its orientation
+    fn vector_entries(&self) -> impl Iterator<Item=&T> {
+        assert!(self.is_vector());
+        self.m.iter().flat_map(|row| row.iter())
+    }
+
+    // Moves the entries out into a single row-major Vec
+    pub fn into_row_major_vec(self) -> Vec<T> {
+        let mut data = Vec::with_capacity(self.rows * self.cols);
+        for row in self.m {
+            data.extend(row);
+        }
+        data
+    }
+
+    // Hands out each row as an owned Vec, leaving this matrix empty
+    pub fn drain_rows(&mut self) -> std::vec::Drain<'_, Vec<T>> {
+        self.rows = 0;
+        self.cols = 0;
+        self.m.drain(..)
+    }
+
+    // Splits into columns 0..j and columns j.., undoing augment
+    pub fn split_cols_at(mut self, j : usize) -> (Matrix<T>, Matrix<T>) {
+        assert!(j <= self.cols);
+
+        let right = self.m.iter_mut().map(|row| row.split_off(j)).collect();
+        let right = Matrix { m : right, rows : self.rows, cols : self.cols - j };
+        self.cols = j;
+        (self, right)
+    }
+
+    pub fn row(&self, i : usize) -> &[T] {
+        &self.m[i]
+    }
+
+    // Overwrites entry (i, j) with f(i, j), keeping the existing storage
+    pub fn fill_with<F>(&mut self, mut f : F) where F : FnMut(usize, usize) -> T {
+        for (i, row) in self.m.iter_mut().enumerate() {
+            for (j, x) in row.iter_mut().enumerate() {
+                *x = f(i, j);
+            }
+        }
+    }
+}
+
+impl<T : Clone> Matrix<T> {
+    // Overwrites row i, which must have exactly cols entries
+    pub fn set_row(&mut self, i : usize, row : &[T]) {
+        assert!(row.len() == self.cols);
+        self.m[i].clone_from_slice(row);
+    }
+
+    // Copy of column j
+    pub fn column(&self, j : usize) -> Vec<T> {
+        assert!(j < self.cols);
+        self.m.iter().map(|row| row[j].clone()).collect()
+    }
+
+    // Overwrites column j, which must have exactly rows entries
+    pub fn set_column(&mut self, j : usize, column : &[T]) {
+        assert!(j < self.cols);
+        assert!(column.len() == self.rows);
+
+        for (row, x) in self.m.iter_mut().zip(column.iter()) {
+            row[j] = x.clone();
+        }
+    }
+
+    // Sets every entry to value, keeping the existing storage
+    pub fn fill(&mut self, value : T) {
+        for row in self.m.iter_mut() {
+            row.fill(value.clone());
+        }
+    }
+
+    // [A | B], the columns of other placed to the right of these, e.g. a
+    // coefficient matrix augmented with right hand sides for rref
+    pub fn augment(&self, other : &Matrix<T>) -> Matrix<T> {
+        assert!(self.rows == other.rows);
+
+        let m = self.m.iter().zip(other.m.iter())
+            .map(|(a, b)| a.iter().chain(b.iter()).cloned().collect())
+            .collect();
+        Matrix { m, rows : self.rows, cols : self.cols + other.cols }
+    }
+
+    // Overwrites the main diagonal, which has min(rows, cols) entries
+    pub fn set_diagonal(&mut self, diagonal : &[T]) {
+        assert!(diagonal.len() == self.rows.min(self.cols));
+
+        for (i, x) in diagonal.iter().enumerate() {
+            self.m[i][i] = x.clone();
+        }
+    }
+}
+
+impl<T : AddAssign+Clone> Matrix<T> {
+    // Adds value to each diagonal entry in place, e.g. A + λI for regularisation
+    pub fn add_to_diagonal(&mut self, value : T) {
+        for i in 0..self.rows.min(self.cols) {
+            self.m[i][i] += value.clone();
+        }
+    }
+}
+
+impl<T : MulAssign+Clone> Matrix<T> {
+    // Multiplies each diagonal entry by value in place
+    pub fn scale_diagonal(&mut self, value : T) {
+        for i in 0..self.rows.min(self.cols) {
+            self.m[i][i] *= value.clone();
+        }
+    }
+}
+
+impl<T : AddAssign+Clone+Default+Mul<Output=T>> Matrix<T> {
+    // Dot product of two vectors with the same number of entries
+    pub fn dot(&self, other : &Matrix<T>) -> T {
+        assert!(self.rows * self.cols == other.rows * other.cols);
+
+        let mut total = Default::default();
+        for (a, b) in self.vector_entries().zip(other.vector_entries()) {
+            total += a.clone() * b.clone();
+        }
+        total
+    }
+
+    // Matrix-vector product Ax
+    pub fn mul_vec(&self, x : &[T]) -> Vec<T> {
+        assert!(x.len() == self.cols);
+
+        self.m.iter().map(|row| {
+            let mut total = Default::default();
+            for (a, b) in row.iter().zip(x.iter()) {
+                total += a.clone() * b.clone();
+            }
+            total
+        }).collect()
+    }
+
+    // Dot product of rows i and j
+    pub fn row_dot(&self, i : usize, j : usize) -> T {
+        let mut total = Default::default();
+        for (a, b) in self.m[i].iter().zip(self.m[j].iter()) {
+            total += a.clone() * b.clone();
+        }
+        total
+    }
+
+    // Dot product of columns i and j
+    pub fn col_dot(&self, i : usize, j : usize) -> T {
+        let mut total = Default::default();
+        for row in self.m.iter() {
+            total += row[i].clone() * row[j].clone();
+        }
+        total
+    }
+
+    // Computes the Gram matrix AᵀA, only working out the upper triangle
+    // since the result is symmetric
+    pub fn gram(&self) -> Matrix<T> {
+        let mut matrix = Matrix::new(self.cols, self.cols);
+
+        for i in 0..self.cols {
+            for j in i..self.cols {
+                let entry = self.col_dot(i, j);
+                if i != j {
+                    matrix.m[j][i] = entry.clone();
+                }
+                matrix.m[i][j] = entry;
+            }
+        }
+
+        matrix
+    }
+
+    // A·Bᵀ without forming Bᵀ: entry (i, j) is row i of A dotted with row j
+    // of B, so both operands are read along their rows
+    pub fn mul_transpose(&self, other : &Matrix<T>) -> Matrix<T> {
+        assert!(self.cols == other.cols);
+
+        Matrix::from_fn(self.rows, other.rows, |i, j| {
+            let mut total = T::default();
+            for (a, b) in self.m[i].iter().zip(other.m[j].iter()) {
+                total += a.clone() * b.clone();
+            }
+            total
+        })
+    }
+
+    // Aᵀ·B without forming Aᵀ, accumulated as a sum of outer products of
+    // matching rows
+    pub fn transpose_mul(&self, other : &Matrix<T>) -> Matrix<T> {
+        assert!(self.rows == other.rows);
+
+        let mut matrix = Matrix::new(self.cols, other.cols);
+        for (a, b) in self.m.iter().zip(other.m.iter()) {
+            for (i, x) in a.iter().enumerate() {
+                for (j, y) in b.iter().enumerate() {
+                    matrix.m[i][j] += x.clone() * y.clone();
+                }
+            }
+        }
+
+        matrix
+    }
+}
+
+// For entries like big integers and rationals, which can be multiplied by
+// reference, a product which never clones an entry
+impl<T : AddAssign+Clone+Default> Matrix<T> where for<'a> &'a T : Mul<&'a T, Output=T> {
+    pub fn mul_ref(&self, rhs : &Matrix<T>) -> Matrix<T> {
+        assert!(self.cols == rhs.rows);
+
+        let mut matrix = Matrix::new(self.rows, rhs.cols);
+        for (out, a) in matrix.m.iter_mut().zip(self.m.iter()) {
+            for (x, b) in a.iter().zip(rhs.m.iter()) {
+                for (o, y) in out.iter_mut().zip(b.iter()) {
+                    *o += x * y;
+                }
+            }
+        }
+
+        matrix
+    }
+}
+
+// Structural checks; pass a zero tolerance for exact types
+impl<T : Clone+PartialOrd+Sub<Output=T>> Matrix<T> {
+    pub fn is_symmetric(&self, tol : T) -> bool {
+        self.is_square() && (0..self.rows).all(|i| {
+            (0..i).all(|j| within_tolerance(&self.m[i][j], &self.m[j][i], &tol))
+        })
+    }
+}
+
+impl<T : Clone+Default+PartialOrd+Sub<Output=T>> Matrix<T> {
+    // Checks that every entry (i, j) selected by the predicate is within tol of zero
+    fn entries_vanish<F>(&self, tol : &T, select : F) -> bool
+        where F : Fn(usize, usize) -> bool {
+        let zero = Default::default();
+        self.m.iter().enumerate().all(|(i, row)| {
+            row.iter().enumerate().all(|(j, x)| !select(i, j) || within_tolerance(x, &zero, tol))
+        })
+    }
+
+    pub fn is_diagonal(&self, tol : T) -> bool {
+        self.entries_vanish(&tol, |i, j| i != j)
+    }
+
+    // Everything below the main diagonal is zero
+    pub fn is_upper_triangular(&self, tol : T) -> bool {
+        self.entries_vanish(&tol, |i, j| i > j)
+    }
+
+    // Everything above the main diagonal is zero
+    pub fn is_lower_triangular(&self, tol : T) -> bool {
+        self.entries_vanish(&tol, |i, j| i < j)
+    }
+
+    // Everything off the three central diagonals is zero
+    pub fn is_tridiagonal(&self, tol : T) -> bool {
+        self.entries_vanish(&tol, |i, j| i > j + 1 || j > i + 1)
+    }
+}
+
+impl<T : Clone+Default+One> Matrix<T> {
+    pub fn identity(n : usize) -> Self {
+        let mut matrix = Matrix::new(n, n);
+        for i in 0..n {
+            matrix.m[i][i] = T::one();
+        }
+        matrix
+    }
+}
+
+impl<T : Clone+Default+One+PartialOrd+Sub<Output=T>> Matrix<T> {
+    pub fn is_identity(&self, tol : T) -> bool {
+        let (zero, one) = (Default::default(), T::one());
+        self.is_square() && self.m.iter().enumerate().all(|(i, row)| {
+            row.iter().enumerate().all(|(j, x)| {
+                within_tolerance(x, if i == j { &one } else { &zero }, &tol)
+            })
+        })
+    }
+}
+
+impl<T : AddAssign+Clone+Default+Mul<Output=T>+One+PartialOrd+Sub<Output=T>> Matrix<T> {
+    // Checks AᵀA = I, i.e. that the columns are orthonormal
+    pub fn is_orthogonal(&self, tol : T) -> bool {
+        self.is_square() && self.gram().is_identity(tol)
+    }
+}
+
+// A = symmetrize(A) + antisymmetric_part(A). For integer types the halving
+// rounds towards zero, so the split is only exact for floats and rationals.
+impl<T : Clone+One+Add<Output=T>+Sub<Output=T>+Div<Output=T>> Matrix<T> {
+    // Symmetric part (A + Aᵀ)/2
+    pub fn symmetrize(&self) -> Matrix<T> {
+        assert!(self.is_square());
+
+        let two = T::one() + T::one();
+        Matrix::from_fn(self.rows, self.cols, |i, j| {
+            (self.m[i][j].clone() + self.m[j][i].clone()) / two.clone()
+        })
+    }
+
+    // Antisymmetric part (A - Aᵀ)/2
+    pub fn antisymmetric_part(&self) -> Matrix<T> {
+        assert!(self.is_square());
+
+        let two = T::one() + T::one();
+        Matrix::from_fn(self.rows, self.cols, |i, j| {
+            (self.m[i][j].clone() - self.m[j][i].clone()) / two.clone()
+        })
+    }
+}
+
+impl<T : Clone+Mul<Output=T>+Sub<Output=T>> Matrix<T> {
+    // Cross product of two 3-vectors, returned with the same orientation as self
+    pub fn cross(&self, other : &Matrix<T>) -> Matrix<T> {
+        assert!(self.rows * self.cols == 3);
+        assert!(other.rows * other.cols == 3);
+
+        let a : Vec<&T> = self.vector_entries().collect();
+        let b : Vec<&T> = other.vector_entries().collect();
+        let c = vec![
+            a[1].clone() * b[2].clone() - a[2].clone() * b[1].clone(),
+            a[2].clone() * b[0].clone() - a[0].clone() * b[2].clone(),
+            a[0].clone() * b[1].clone() - a[1].clone() * b[0].clone(),
+        ];
+
+        let m = if self.rows == 1 {
+            vec![c]
         } else {
-            let mut matrix = Matrix::new(other.len(),other[0].len());
-            matrix.m = other;
-            return matrix;
+            c.into_iter().map(|x| vec![x]).collect()
+        };
+
+        Matrix { m, rows : self.rows, cols : self.cols }
+    }
+}
+
+// For every conversion from a list of rows, an empty list gives a matrix
+// with no rows and as many columns as the row type fixes: C for arrays, and
+// 0 for vectors and slices, whose rows have no length to read.
+impl<T> From<Vec<Vec<T>>> for Matrix<T> {
+    fn from(other: Vec<Vec<T>>) -> Self {
+        let cols = other.first().map_or(0, |row| row.len());
+        Matrix { rows : other.len(), m : other, cols }
+    }
+}
+
+impl<T, const R : usize, const C : usize> From<[[T; C]; R]> for Matrix<T> {
+    fn from(other : [[T; C]; R]) -> Self {
+        let m = Vec::from(other).into_iter().map(Vec::from).collect();
+        Matrix { m, rows : R, cols : C }
+    }
+}
+
+impl<T : Clone> TryFrom<&[&[T]]> for Matrix<T> {
+    type Error = MatrixError;
+
+    fn try_from(other : &[&[T]]) -> Result<Self, MatrixError> {
+        let cols = other.first().map_or(0, |row| row.len());
+
+        for (i, row) in other.iter().enumerate() {
+            if row.len() != cols {
+                return Err(MatrixError::RaggedRows { row : i, expected : cols, found : row.len() });
+            }
         }
+
+        let m = other.iter().map(|row| row.to_vec()).collect();
+        Ok(Matrix { m, rows : other.len(), cols })
     }
 }
 
-impl<'a, 'b, T : AddAssign+Clone> Add<&'b Matrix<T>> for &'a Matrix<T> {
+impl<T : AddAssign+Clone> Add<&Matrix<T>> for &Matrix<T> {
     type Output = Matrix<T>;
 
-    fn add(self, other: &'b Matrix<T>) -> Matrix<T> {
+    fn add(self, other: &Matrix<T>) -> Matrix<T> {
         // can only add matrices of the same size
         assert!(self.rows == other.rows);
         assert!(self.cols == other.cols);
@@ -87,33 +632,113 @@ impl<T: AddAssign+Clone> Add for Matrix<T> {
         assert!(self.rows == other.rows);
         assert!(self.cols == other.cols);
 
+        // both sides are owned, so move the right hand entries across
+        for (x, y) in self.m.iter_mut().flatten().zip(other.m.into_iter().flatten()) {
+            *x += y;
+        }
+
+        self
+    }
+}
+
+impl<T : AddAssign+Clone> AddAssign<&Matrix<T>> for Matrix<T> {
+    fn add_assign(&mut self, other : &Matrix<T>) {
+        // can only add matrices of the same size
+        assert!(self.rows == other.rows);
+        assert!(self.cols == other.cols);
+
         for i in 0..self.rows {
             for j in 0..self.cols {
                 self.m[i][j] += other.m[i][j].clone();
             }
         }
+    }
+}
+
+impl<T : AddAssign+Clone> Add<&Matrix<T>> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn add(mut self, other : &Matrix<T>) -> Matrix<T> {
+        self += other;
+        self
+    }
+}
+
+impl<T : AddAssign+Clone> Add<Matrix<T>> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn add(self, other : Matrix<T>) -> Matrix<T> {
+        // addition commutes, so accumulate into the owned right hand side
+        other + self
+    }
+}
+
+impl<T : SubAssign+Clone> SubAssign<&Matrix<T>> for Matrix<T> {
+    fn sub_assign(&mut self, other : &Matrix<T>) {
+        // can only subtract matrices of the same size
+        assert!(self.rows == other.rows);
+        assert!(self.cols == other.cols);
+
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                self.m[i][j] -= other.m[i][j].clone();
+            }
+        }
+    }
+}
+
+impl<T : SubAssign+Clone> Sub<&Matrix<T>> for Matrix<T> {
+    type Output = Matrix<T>;
 
+    fn sub(mut self, other : &Matrix<T>) -> Matrix<T> {
+        self -= other;
         self
     }
 }
 
-impl<'a, 'b, T: AddAssign+Clone+Default+Mul<Output=T>> Mul<&'b Matrix<T>> for &'a Matrix<T> {
+impl<T : SubAssign+Clone> Sub<&Matrix<T>> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn sub(self, other : &Matrix<T>) -> Matrix<T> {
+        self.clone() - other
+    }
+}
+
+impl<T : SubAssign+Clone> Sub for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn sub(self, other : Matrix<T>) -> Matrix<T> {
+        self - &other
+    }
+}
+
+impl<T : SubAssign+Clone> Sub<Matrix<T>> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn sub(self, other : Matrix<T>) -> Matrix<T> {
+        // T only promises SubAssign, so the left hand side needs its own copy
+        self.clone() - &other
+    }
+}
+
+impl<T: AddAssign+Clone+Default+Mul<Output=T>> Mul<&Matrix<T>> for &Matrix<T> {
     type Output = Matrix<T>;
 
-    fn mul(self, rhs: &'b Matrix<T>) -> Matrix<T> {
+    fn mul(self, rhs: &Matrix<T>) -> Matrix<T> {
         // can only multiply if LHS.cols == RHS.rows
         assert!(self.cols == rhs.rows);
 
         let mut matrix = Matrix::new(self.rows, rhs.cols);
 
-        // TODO - research "better" matrix multiplication algos
-        for i in 0..self.rows {
-            for j in 0..rhs.cols {
-                let mut entry = Default::default();
-                for k in 0..self.cols {
-                    entry += self.m[i][k].clone() * rhs.m[k][j].clone();
+        // i-k-j order: each row of the result accumulates a[i][k] times row
+        // k of rhs, so every inner loop runs along contiguous rows and
+        // vectorises for Copy entries. Each entry of the result still sums
+        // over k in increasing order.
+        for (out, a) in matrix.m.iter_mut().zip(self.m.iter()) {
+            for (x, b) in a.iter().zip(rhs.m.iter()) {
+                for (o, y) in out.iter_mut().zip(b.iter()) {
+                    *o += x.clone() * y.clone();
                 }
-                matrix.m[i][j] = entry;
             }
         }
 
@@ -130,9 +755,26 @@ impl<T: AddAssign+Clone+Default+Mul<Output=T>> Mul for Matrix<T> {
     }
 }
 
-impl<T> Into<Vec<Vec<T>>> for Matrix<T> {
-    fn into(self) -> Vec<Vec<T>> {
-        self.m
+// A product can't be formed in place, so owning either side gains nothing
+impl<T: AddAssign+Clone+Default+Mul<Output=T>> Mul<&Matrix<T>> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs : &Matrix<T>) -> Matrix<T> {
+        &self * rhs
+    }
+}
+
+impl<T: AddAssign+Clone+Default+Mul<Output=T>> Mul<Matrix<T>> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs : Matrix<T>) -> Matrix<T> {
+        self * &rhs
+    }
+}
+
+impl<T> From<Matrix<T>> for Vec<Vec<T>> {
+    fn from(matrix : Matrix<T>) -> Vec<Vec<T>> {
+        matrix.m
     }
 }
 
@@ -144,9 +786,34 @@ impl<T> Index<(usize,usize)> for Matrix<T> {
     }
 }
 
+// m[i] is row i, so m[i][j] works alongside m[(i, j)]
+impl<T> Index<usize> for Matrix<T> {
+    type Output = [T];
+
+    fn index(&self, i : usize) -> &[T] {
+        &self.m[i]
+    }
+}
+
+// Only hands out a slice, so the row can't change length
+impl<T> IndexMut<usize> for Matrix<T> {
+    fn index_mut(&mut self, i : usize) -> &mut [T] {
+        &mut self.m[i]
+    }
+}
+
+// Shared by the float tests: same shape, and every entry within tol
+#[cfg(test)]
+pub(crate) fn assert_close(a : &Matrix<f64>, b : &Matrix<f64>, tol : f64) {
+    assert_eq!((a.rows, a.cols), (b.rows, b.cols));
+    assert!((a - b).entries_vanish(&tol, |_, _| true), "{:?} vs {:?}", a, b);
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Matrix;
+    use std::convert::TryFrom;
+
+    use super::{Matrix, MatrixError};
 
     #[test]
     fn basic_matrix_transpose() {
@@ -167,6 +834,21 @@ mod tests {
         assert_eq!(m+n, r);
     }
 
+    #[test]
+    fn owned_matrix_add() {
+        use num_rational::Ratio;
+
+        // both operands by value: the right hand entries are moved into the
+        // left hand matrix rather than cloned
+        assert_eq!(Matrix::from([[1,2]]) + Matrix::from([[3,4]]), Matrix::from([[4,6]]));
+
+        let r = |n, d| Ratio::new(n, d);
+        let a = Matrix::from([[r(1, 2),r(1, 3)],[r(-1, 1),r(2, 5)]]);
+        let b = Matrix::from([[r(1, 2),r(-1, 3)],[r(3, 4),r(1, 5)]]);
+        assert_eq!(a.clone() + b.clone(), &a + &b);
+        assert_eq!(a + b, Matrix::from([[r(1, 1),r(0, 1)],[r(-1, 4),r(3, 5)]]));
+    }
+
     #[test]
     fn basic_matrix_multiply() {
         let m = Matrix::from(vec![vec![2, 1], vec![-1, 1]]);
@@ -192,4 +874,311 @@ mod tests {
 
         assert_eq!(n, p);
     }
+
+    #[test]
+    fn basic_vector_dot() {
+        let u = Matrix::from(vec![vec![1,2,3]]);
+        let v = Matrix::from(vec![vec![4],vec![-5],vec![6]]);
+
+        assert_eq!(u.dot(&v), 12);
+        assert_eq!(v.dot(&u), 12);
+    }
+
+    #[test]
+    fn basic_vector_cross() {
+        let u = Matrix::from(vec![vec![1,0,0]]);
+        let v = Matrix::from(vec![vec![0,1,0]]);
+        assert_eq!(u.cross(&v), Matrix::from(vec![vec![0,0,1]]));
+
+        let a = Matrix::from(vec![vec![2],vec![3],vec![4]]);
+        let b = Matrix::from(vec![vec![5],vec![6],vec![7]]);
+        let c = a.cross(&b);
+        assert_eq!(c, Matrix::from(vec![vec![-3],vec![6],vec![-3]]));
+
+        // the cross product is orthogonal to both inputs
+        assert_eq!(c.dot(&a), 0);
+        assert_eq!(c.dot(&b), 0);
+    }
+
+    #[test]
+    fn basic_row_and_column_dot() {
+        let m = Matrix::from(vec![vec![1,2],vec![3,4],vec![5,6]]);
+
+        assert_eq!(m.row_dot(0, 2), 17);
+        assert_eq!(m.col_dot(0, 1), 44);
+        assert_eq!(m.col_dot(1, 1), 56);
+    }
+
+    #[test]
+    fn basic_matrix_gram() {
+        let m = Matrix::from(vec![vec![1,2,0],vec![3,4,-1],vec![5,6,2]]);
+        let mut t = m.clone();
+        t.transpose();
+
+        assert_eq!(m.gram(), t * m);
+    }
+
+    #[test]
+    fn matrix_from_array() {
+        let m = Matrix::from([[1,2],[3,4],[5,6]]);
+
+        assert_eq!(m, Matrix::from(vec![vec![1,2],vec![3,4],vec![5,6]]));
+    }
+
+    #[test]
+    fn matrix_try_from_slices() {
+        let data : [&[i32]; 2] = [&[1,2,3], &[4,5,6]];
+        let m = Matrix::try_from(&data[..]).unwrap();
+        assert_eq!(m, Matrix::from([[1,2,3],[4,5,6]]));
+
+        let ragged : [&[i32]; 2] = [&[1,2,3], &[4,5]];
+        assert_eq!(Matrix::try_from(&ragged[..]),
+                   Err(MatrixError::RaggedRows { row : 1, expected : 3, found : 2 }));
+    }
+
+    #[test]
+    fn matrix_from_empty_rows() {
+        // an array's row type fixes the column count even with no rows
+        let a = Matrix::from([[0i32; 3]; 0]);
+        assert_eq!((a.rows(), a.cols()), (0, 3));
+        assert_eq!(a, Matrix::new(0, 3));
+        assert_eq!((&a * &Matrix::new(3, 2)).cols(), 2);
+
+        // vectors and slices of rows don't, so they give 0×0
+        let v = Matrix::<i32>::from(Vec::<Vec<i32>>::new());
+        assert_eq!((v.rows(), v.cols()), (0, 0));
+        let empty : [&[i32]; 0] = [];
+        assert_eq!(Matrix::try_from(&empty[..]), Ok(v.clone()));
+        assert_eq!(Matrix::from_rows(Vec::<Vec<i32>>::new()), Ok(v));
+    }
+
+    #[test]
+    fn matrix_constructors_without_default() {
+        use std::num::NonZeroU32;
+
+        let text = "1 2 3\n4 5 6";
+        let parsed = Matrix::from_rows(text.lines().map(|line| {
+            line.split(' ').map(|x| x.parse::<NonZeroU32>().unwrap())
+        })).unwrap();
+        assert_eq!((parsed.rows(), parsed.cols()), (2, 3));
+        assert_eq!(parsed[(1, 2)].get(), 6);
+
+        assert_eq!(Matrix::from_rows(vec![vec![1,2], vec![3]]),
+                   Err(MatrixError::RaggedRows { row : 1, expected : 2, found : 1 }));
+        assert_eq!(Matrix::from_rows(Vec::<Vec<i32>>::new()).unwrap().rows(), 0);
+
+        let m = Matrix::from_row_major(2, 3, (1..=6).map(|x| NonZeroU32::new(x).unwrap()));
+        assert_eq!(m, parsed);
+        assert_eq!(Matrix::from_fn(2, 2, |i, j| i * 2 + j), Matrix::from([[0,1],[2,3]]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn matrix_from_row_major_wrong_count() {
+        Matrix::from_row_major(2, 2, vec![1,2,3]);
+    }
+
+    #[test]
+    fn matrix_into_row_major_vec() {
+        let m = Matrix::from([[1,2,3],[4,5,6]]);
+
+        assert_eq!(m.into_row_major_vec(), vec![1,2,3,4,5,6]);
+    }
+
+    #[test]
+    fn matrix_drain_rows() {
+        let mut m = Matrix::from([[1,2],[3,4]]);
+        let rows : Vec<Vec<i32>> = m.drain_rows().collect();
+
+        assert_eq!(rows, vec![vec![1,2],vec![3,4]]);
+        assert_eq!(m, Matrix::from(Vec::<Vec<i32>>::new()));
+    }
+
+    #[test]
+    fn matrix_structure_predicates() {
+        let s = Matrix::from([[1,2,3],[2,5,6],[3,6,9]]);
+        assert!(s.is_square());
+        assert!(s.is_symmetric(0));
+        assert!(!s.is_diagonal(0));
+
+        let u = Matrix::from([[1,2,3],[0,5,6],[0,0,9]]);
+        assert!(u.is_upper_triangular(0));
+        assert!(!u.is_lower_triangular(0));
+        assert!(!u.is_symmetric(0));
+
+        let d = Matrix::from([[4u32,0],[0,7]]);
+        assert!(d.is_diagonal(0));
+        assert!(d.is_upper_triangular(0) && d.is_lower_triangular(0));
+
+        let r = Matrix::from([[1,2,3],[4,5,6]]);
+        assert!(!r.is_square());
+        assert!(!r.is_symmetric(0));
+    }
+
+    #[test]
+    fn matrix_structure_predicates_with_tolerance() {
+        let m = Matrix::from([[1.0,1e-9],[-1e-9,2.0]]);
+
+        assert!(!m.is_diagonal(0.0));
+        assert!(m.is_diagonal(1e-6));
+        assert!(m.is_symmetric(1e-6));
+    }
+
+    #[test]
+    fn matrix_identity_and_orthogonal_checks() {
+        assert!(Matrix::from([[1,0],[0,1]]).is_identity(0));
+        assert!(!Matrix::from([[1,0],[1,1]]).is_identity(0));
+        assert!(!Matrix::from([[1,0,0]]).is_identity(0));
+
+        // a permutation matrix is orthogonal
+        let p = Matrix::from([[0,1,0],[0,0,1],[1,0,0]]);
+        assert!(p.is_orthogonal(0));
+
+        let h = 0.5f64.sqrt();
+        let r = Matrix::from([[h,-h],[h,h]]);
+        assert!(r.is_orthogonal(1e-12));
+        assert!(!Matrix::from([[1.0,1.0],[0.0,1.0]]).is_orthogonal(1e-12));
+    }
+
+    #[test]
+    fn matrix_transposed_products() {
+        let a = Matrix::from([[1,2,3],[4,5,6]]);
+        let b = Matrix::from([[0,1,-1],[2,0,1],[1,1,1],[3,-2,0]]);
+
+        let mut bt = b.clone();
+        bt.transpose();
+        assert_eq!(a.mul_transpose(&b), &a * &bt);
+
+        let mut at = a.clone();
+        at.transpose();
+        let c = Matrix::from([[1,0],[2,-1]]);
+        assert_eq!(a.transpose_mul(&c), &at * &c);
+        assert_eq!(a.transpose_mul(&a), a.gram());
+    }
+
+    #[test]
+    fn matrix_product_by_reference() {
+        use num_rational::Ratio;
+
+        let r = |n, d| Ratio::new(n, d);
+        let a = Matrix::from([[r(1, 2),r(1, 3)],[r(-1, 1),r(2, 5)],[r(0, 1),r(7, 4)]]);
+        let b = Matrix::from([[r(2, 1),r(0, 1),r(1, 6)],[r(3, 1),r(-1, 2),r(1, 1)]]);
+
+        assert_eq!(a.mul_ref(&b), &a * &b);
+        assert_eq!(a.mul_ref(&b)[(0, 2)], r(5, 12));
+    }
+
+    #[test]
+    fn matrix_mixed_ownership_operators() {
+        let a = Matrix::from([[1,2],[3,4]]);
+        let b = Matrix::from([[5,6],[7,8]]);
+
+        let sum = Matrix::from([[6,8],[10,12]]);
+        assert_eq!(a.clone() + &b, sum);
+        assert_eq!(&a + b.clone(), sum);
+
+        let difference = Matrix::from([[-4,-4],[-4,-4]]);
+        assert_eq!(&a - &b, difference);
+        assert_eq!(a.clone() - &b, difference);
+        assert_eq!(&a - b.clone(), difference);
+        assert_eq!(a.clone() - b.clone(), difference);
+
+        let product = &a * &b;
+        assert_eq!(a.clone() * &b, product);
+        assert_eq!(&a * b.clone(), product);
+        assert_eq!(a * b, product);
+    }
+
+    #[test]
+    fn matrix_compound_assignment() {
+        let delta = Matrix::from([[1,-1],[2,0]]);
+        let mut acc = Matrix::from([[0,0],[0,0]]);
+
+        for _ in 0..3 {
+            acc += &delta;
+        }
+        assert_eq!(acc, Matrix::from([[3,-3],[6,0]]));
+
+        acc -= &delta;
+        assert_eq!(acc, Matrix::from([[2,-2],[4,0]]));
+        assert_eq!(delta, Matrix::from([[1,-1],[2,0]]));
+    }
+
+    #[test]
+    fn matrix_row_indexing() {
+        let mut a = Matrix::from([[1,2,3],[4,5,6]]);
+        assert_eq!(a[1], [4,5,6]);
+        assert_eq!(a[0][2], a[(0, 2)]);
+
+        a[1][0] = 7;
+        a[0].reverse();
+        assert_eq!(a, Matrix::from([[3,2,1],[7,5,6]]));
+    }
+
+    #[test]
+    fn matrix_row_and_column_access() {
+        let mut a = Matrix::<i32>::new(3, 2);
+        a.set_column(0, &[1,2,3]);
+        a.set_column(1, &[4,5,6]);
+        assert_eq!(a, Matrix::from([[1,4],[2,5],[3,6]]));
+
+        a.set_row(2, &[0,9]);
+        assert_eq!(a.row(2), [0,9]);
+        assert_eq!(a.column(1), vec![4,5,9]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn matrix_set_row_wrong_length() {
+        let mut a = Matrix::<i32>::new(2, 2);
+        a.set_row(0, &[1,2,3]);
+    }
+
+    #[test]
+    fn matrix_fill() {
+        let mut a = Matrix::from([[1,2,3],[4,5,6]]);
+        a.fill(7);
+        assert_eq!(a, Matrix::from([[7,7,7],[7,7,7]]));
+
+        a.fill_with(|i, j| 10 * i + j);
+        assert_eq!(a, Matrix::from([[0,1,2],[10,11,12]]));
+    }
+
+    #[test]
+    fn matrix_diagonal_arithmetic() {
+        let mut a = Matrix::from([[1.0,2.0,3.0],[4.0,5.0,6.0]]);
+        a.add_to_diagonal(0.5);
+        assert_eq!(a, Matrix::from([[1.5,2.0,3.0],[4.0,5.5,6.0]]));
+
+        a.scale_diagonal(2.0);
+        assert_eq!(a, Matrix::from([[3.0,2.0,3.0],[4.0,11.0,6.0]]));
+
+        a.set_diagonal(&[0.0,-1.0]);
+        assert_eq!(a, Matrix::from([[0.0,2.0,3.0],[4.0,-1.0,6.0]]));
+    }
+
+    #[test]
+    fn matrix_triangular_parts() {
+        let a = Matrix::from([[1,2,3],[4,5,6],[7,8,9]]);
+
+        assert_eq!(a.lower_triangular(0), Matrix::from([[1,0,0],[4,5,0],[7,8,9]]));
+        assert_eq!(a.upper_triangular(1), Matrix::from([[0,2,3],[0,0,6],[0,0,0]]));
+        assert_eq!(a.lower_triangular(-1) + a.upper_triangular(0), a);
+
+        let r = Matrix::from([[1,2,3,4],[5,6,7,8]]);
+        assert_eq!(r.lower_triangular(1), Matrix::from([[1,2,0,0],[5,6,7,0]]));
+        assert!(r.upper_triangular(0).is_upper_triangular(0));
+    }
+
+    #[test]
+    fn matrix_symmetric_split() {
+        let a = Matrix::from([[1.0,2.0,0.0],[4.0,5.0,-3.0],[1.0,3.0,9.0]]);
+        let s = a.symmetrize();
+        let k = a.antisymmetric_part();
+
+        assert_eq!(s, Matrix::from([[1.0,3.0,0.5],[3.0,5.0,0.0],[0.5,0.0,9.0]]));
+        assert!(s.is_symmetric(0.0));
+        assert_eq!(k, Matrix::from([[0.0,-1.0,-0.5],[1.0,0.0,-3.0],[0.5,3.0,0.0]]));
+        assert_eq!(&s + &k, a);
+    }
 }
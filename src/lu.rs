@@ -0,0 +1,186 @@
+use std::ops::{Div, Mul, Sub};
+
+use num_traits::One;
+
+use crate::Matrix;
+
+// PA = LU with partial pivoting. L (unit lower triangular) and U are packed
+// into one matrix, and row i of PA is row perm[i] of A. Factorising once
+// and solving for each right hand side costs O(n³) + O(n²k) rather than
+// a fresh O(n³) per system.
+#[derive(Clone,Debug,PartialEq)]
+pub struct Lu<T> {
+    lu : Matrix<T>,
+    perm : Vec<usize>,
+}
+
+impl<T> Matrix<T>
+    where T : Clone+Default+One+PartialOrd+Sub<Output=T>+Mul<Output=T>+Div<Output=T> {
+    // LU factorisation of a square matrix, or None if it is singular
+    pub fn lu(&self) -> Option<Lu<T>> {
+        assert!(self.is_square());
+
+        let n = self.rows;
+        let zero = T::default();
+        let abs = |x : &T| if *x < zero { zero.clone() - x.clone() } else { x.clone() };
+
+        let mut a = self.m.clone();
+        let mut perm : Vec<usize> = (0..n).collect();
+
+        for col in 0..n {
+            // pick the largest remaining entry in this column as the pivot
+            let mut pivot = col;
+            for row in (col + 1)..n {
+                if abs(&a[row][col]) > abs(&a[pivot][col]) {
+                    pivot = row;
+                }
+            }
+            if a[pivot][col] == zero {
+                return None;
+            }
+            a.swap(col, pivot);
+            perm.swap(col, pivot);
+
+            for row in (col + 1)..n {
+                let factor = a[row][col].clone() / a[col][col].clone();
+                for j in (col + 1)..n {
+                    a[row][j] = a[row][j].clone() - factor.clone() * a[col][j].clone();
+                }
+                a[row][col] = factor;
+            }
+        }
+
+        Some(Lu { lu : Matrix { m : a, rows : n, cols : n }, perm })
+    }
+
+    // Solves AX = B for every column of B at once, or None if A is singular
+    pub fn solve(&self, b : &Matrix<T>) -> Option<Matrix<T>> {
+        Some(self.lu()?.solve(b))
+    }
+}
+
+impl<T> Lu<T>
+    where T : Clone+Default+One+PartialOrd+Sub<Output=T>+Mul<Output=T>+Div<Output=T> {
+    // Solves AX = B, where B has one column per right hand side
+    pub fn solve(&self, b : &Matrix<T>) -> Matrix<T> {
+        let n = self.lu.rows;
+        assert!(b.rows == n);
+
+        let a = &self.lu.m;
+        let mut x : Vec<Vec<T>> = self.perm.iter().map(|&i| b.m[i].clone()).collect();
+
+        // forward substitution with the unit lower triangular factor
+        for i in 0..n {
+            for k in 0..i {
+                for j in 0..b.cols {
+                    x[i][j] = x[i][j].clone() - a[i][k].clone() * x[k][j].clone();
+                }
+            }
+        }
+
+        // and backward substitution with the upper factor
+        for i in (0..n).rev() {
+            for k in (i + 1)..n {
+                for j in 0..b.cols {
+                    x[i][j] = x[i][j].clone() - a[i][k].clone() * x[k][j].clone();
+                }
+            }
+            for j in 0..b.cols {
+                x[i][j] = x[i][j].clone() / a[i][i].clone();
+            }
+        }
+
+        Matrix { m : x, rows : n, cols : b.cols }
+    }
+
+    // Solves AᵀX = B with the same factors: Aᵀ = UᵀLᵀP, so forward
+    // substitution with Uᵀ, backward with Lᵀ, then undo the permutation
+    pub fn solve_transpose(&self, b : &Matrix<T>) -> Matrix<T> {
+        let n = self.lu.rows;
+        assert!(b.rows == n);
+
+        let a = &self.lu.m;
+        let mut x = b.m.clone();
+
+        for i in 0..n {
+            for k in 0..i {
+                for j in 0..b.cols {
+                    x[i][j] = x[i][j].clone() - a[k][i].clone() * x[k][j].clone();
+                }
+            }
+            for j in 0..b.cols {
+                x[i][j] = x[i][j].clone() / a[i][i].clone();
+            }
+        }
+        for i in (0..n).rev() {
+            for k in (i + 1)..n {
+                for j in 0..b.cols {
+                    x[i][j] = x[i][j].clone() - a[k][i].clone() * x[k][j].clone();
+                }
+            }
+        }
+
+        let mut m = vec![Vec::new() ; n];
+        for (row, &p) in x.into_iter().zip(self.perm.iter()) {
+            m[p] = row;
+        }
+        Matrix { m, rows : n, cols : b.cols }
+    }
+
+    // The unit lower triangular factor
+    pub fn l(&self) -> Matrix<T> {
+        let n = self.lu.rows;
+        Matrix::from_fn(n, n, |i, j| match i.cmp(&j) {
+            std::cmp::Ordering::Greater => self.lu.m[i][j].clone(),
+            std::cmp::Ordering::Equal => T::one(),
+            std::cmp::Ordering::Less => T::default(),
+        })
+    }
+
+    // The upper triangular factor
+    pub fn u(&self) -> Matrix<T> {
+        self.lu.upper_triangular(0)
+    }
+
+    // Row i of PA is row permutation()[i] of A
+    pub fn permutation(&self) -> &[usize] {
+        &self.perm
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Matrix;
+
+    #[test]
+    fn lu_factors() {
+        let a = Matrix::from([[0.0,2.0,1.0],[1.0,1.0,0.0],[4.0,1.0,3.0]]);
+        let lu = a.lu().unwrap();
+
+        assert_eq!(lu.permutation(), [2,0,1]);
+        assert!(lu.l().is_lower_triangular(0.0) && lu.u().is_upper_triangular(0.0));
+        assert_eq!(&lu.l() * &lu.u(), a.slice(lu.permutation().iter().cloned()));
+
+        assert!(Matrix::from([[1.0,2.0],[2.0,4.0]]).lu().is_none());
+    }
+
+    #[test]
+    fn solve_multiple_right_hand_sides() {
+        use num_rational::Ratio;
+
+        let a = Matrix::from([[2,4,-2],[4,9,-3],[-2,-3,7]]).cast::<Ratio<i64>>();
+        let b = Matrix::from([[2,1,0],[8,0,1],[10,0,0]]).cast::<Ratio<i64>>();
+
+        let x = a.solve(&b).unwrap();
+        assert_eq!(&a * &x, b);
+        assert_eq!(x.column(0), vec![Ratio::from(-1),Ratio::from(2),Ratio::from(2)]);
+
+        let mut at = a.clone();
+        at.transpose();
+        assert_eq!(&at * &a.lu().unwrap().solve_transpose(&b), b);
+
+        let f = Matrix::from([[3.0,1.0],[1.0,2.0]]);
+        let y = f.solve(&Matrix::identity(2)).unwrap();
+        assert!((&f * &y).is_identity(1e-12));
+    }
+}
@@ -0,0 +1,140 @@
+use num_traits::Float;
+
+use crate::iterative::{IterativeSolution, RowEntries};
+use crate::Matrix;
+
+impl<T : Float> Matrix<T> {
+    // Square, with non-negative entries and every row summing to one, all
+    // within tol: the transition matrix of a Markov chain
+    pub fn is_row_stochastic(&self, tol : T) -> bool {
+        self.is_square() && self.m.iter().all(|row| {
+            row.iter().all(|&x| x >= -tol)
+                && (row.iter().fold(T::zero(), |acc, &x| acc + x) - T::one()).abs() <= tol
+        })
+    }
+}
+
+impl<T : Float+Default> Matrix<T> {
+    // The distribution π with πP = π and Σπ = 1 for a row-stochastic P.
+    // Found by a linear solve rather than power iteration, so periodic
+    // chains are fine; returns None if the stationary distribution isn't
+    // unique (the chain has more than one closed class).
+    pub fn stationary_distribution(&self) -> Option<Vec<T>> {
+        assert!(self.is_square());
+
+        // (Pᵀ - I)πᵀ = 0 has one redundant equation, which is swapped for
+        // the normalisation Σπ = 1
+        let n = self.rows;
+        let a = Matrix::from_fn(n, n, |i, j| {
+            if i == n - 1 {
+                T::one()
+            } else if i == j {
+                self.m[j][i] - T::one()
+            } else {
+                self.m[j][i]
+            }
+        });
+
+        let e_n = Matrix::from_fn(n, 1, |i, _| if i == n - 1 { T::one() } else { T::zero() });
+        let pi = a.solve(&e_n)?;
+        Some(pi.m.into_iter().map(|row| row[0]).collect())
+    }
+}
+
+// PageRank of the graph with weighted adjacency matrix a, where a_ij is the
+// weight of the edge i → j (dense, sparse or banded). A random surfer
+// follows an out-edge with probability damping and otherwise jumps to a
+// uniformly random vertex; vertices without out-edges jump uniformly
+// always. Iterates until the ranks change by at most tol in the 1-norm.
+pub fn pagerank<T, A>(a : &A, damping : T, tol : T, max_iter : usize) -> IterativeSolution<T>
+    where T : Float, A : RowEntries<T> {
+    assert!(a.rows() == a.cols());
+
+    let n = a.rows();
+    let size = T::from(n).unwrap();
+    let out_degree : Vec<T> = (0..n).map(|i| {
+        let mut total = T::zero();
+        a.for_each_in_row(i, |_, &x| total = total + x);
+        total
+    }).collect();
+
+    let mut rank = vec![size.recip() ; n];
+    let mut change = T::infinity();
+    let mut iterations = 0;
+
+    while iterations < max_iter && change > tol {
+        let dangling = (0..n)
+            .filter(|&i| out_degree[i] == T::zero())
+            .fold(T::zero(), |acc, i| acc + rank[i]);
+        let base = ((T::one() - damping) + damping * dangling) / size;
+
+        let mut next = vec![base ; n];
+        for i in 0..n {
+            if out_degree[i] == T::zero() {
+                continue;
+            }
+            let share = damping * rank[i] / out_degree[i];
+            a.for_each_in_row(i, |j, &x| next[j] = next[j] + share * x);
+        }
+
+        change = rank.iter().zip(next.iter()).fold(T::zero(), |acc, (&r, &s)| acc + (r - s).abs());
+        rank = next;
+        iterations += 1;
+    }
+
+    IterativeSolution { x : rank, iterations, residual : change, converged : change <= tol }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::pagerank;
+    use crate::{Matrix, SparseMatrix};
+
+    #[test]
+    fn stochastic_check() {
+        assert!(Matrix::from([[0.9,0.1],[0.5,0.5]]).is_row_stochastic(1e-12));
+        assert!(!Matrix::from([[0.9,0.2],[0.5,0.5]]).is_row_stochastic(1e-12));
+        assert!(!Matrix::from([[1.5,-0.5],[0.5,0.5]]).is_row_stochastic(1e-12));
+    }
+
+    #[test]
+    fn stationary_distributions() {
+        let p = Matrix::from([[0.9f64,0.1],[0.5,0.5]]);
+        let pi = p.stationary_distribution().unwrap();
+        assert!((pi[0] - 5.0 / 6.0).abs() < 1e-12 && (pi[1] - 1.0 / 6.0).abs() < 1e-12);
+
+        // periodic: power iteration would oscillate forever
+        let cycle = Matrix::from([[0.0f64,1.0,0.0],[0.0,0.0,1.0],[1.0,0.0,0.0]]);
+        for x in cycle.stationary_distribution().unwrap() {
+            assert!((x - 1.0 / 3.0).abs() < 1e-12);
+        }
+
+        // two absorbing states: no unique answer
+        assert_eq!(Matrix::from([[1.0,0.0],[0.0,1.0]]).stationary_distribution(), None);
+        let split = Matrix::from([[0.5,0.5,0.0,0.0],[0.5,0.5,0.0,0.0],[0.0,0.0,0.3,0.7],[0.0,0.0,0.6,0.4]]);
+        assert_eq!(split.stationary_distribution(), None);
+    }
+
+    #[test]
+    fn pagerank_with_dangling_node() {
+        // 0 → 1, 0 → 2, 1 → 2, 2 → 0, and 3 → 2 with nothing linking out of 4
+        let mut a = Matrix::<f64>::new(5, 5);
+        for &(i, j) in [(0, 1), (0, 2), (1, 2), (2, 0), (3, 2)].iter() {
+            a[i][j] = 1.0;
+        }
+
+        let dense = pagerank(&a, 0.85, 1e-12, 200);
+        assert!(dense.converged);
+        assert!((dense.x.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+
+        let expected = [0.35906203, 0.18874594, 0.37990288, 0.03614458, 0.03614458];
+        for (r, e) in dense.x.iter().zip(expected.iter()) {
+            assert!((r - e).abs() < 1e-6);
+        }
+
+        let sparse = pagerank(&SparseMatrix::from_dense(&a), 0.85, 1e-12, 200);
+        for (r, s) in dense.x.iter().zip(sparse.x.iter()) {
+            assert!((r - s).abs() < 1e-12);
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use std::ops::{AddAssign, Mul};
+
+use crate::Matrix;
+
+impl<T : Clone> Matrix<T> {
+    // Product with each entry widened to A as it is read and the sums
+    // accumulated in A, e.g. a.mul_accumulate::<f64>(&b) for f32 inputs, or
+    // ::<i32> for i8 ones. The result is what widening both matrices first
+    // would give, without storing the wide copies. The generic kernel
+    // accumulates in T, so over a long inner dimension it loses low order
+    // bits (or overflows) that a wider accumulator keeps.
+    pub fn mul_accumulate<A>(&self, other : &Matrix<T>) -> Matrix<A>
+        where T : Into<A>, A : AddAssign+Clone+Default+Mul<Output=A> {
+        assert!(self.cols == other.rows);
+
+        let mut m = vec![vec![A::default() ; other.cols] ; self.rows];
+        for (out, row) in m.iter_mut().zip(self.m.iter()) {
+            for (x, other_row) in row.iter().zip(other.m.iter()) {
+                let x : A = x.clone().into();
+                for (acc, y) in out.iter_mut().zip(other_row.iter()) {
+                    *acc += x.clone() * y.clone().into();
+                }
+            }
+        }
+        Matrix { m, rows : self.rows, cols : other.cols }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Matrix;
+
+    #[test]
+    fn wide_accumulation() {
+        // 10⁸ followed by a thousand ones: each + 1 is below half an f32 ulp
+        // at 10⁸ (which is 8), so an f32 sum never moves
+        let n = 1001;
+        let row = Matrix::from_fn(1, n, |_, j| if j == 0 { 1.0e8f32 } else { 1.0 });
+        let col = Matrix::from_fn(n, 1, |_, _| 1.0f32);
+
+        assert_eq!((&row * &col)[(0, 0)], 1.0e8);
+        assert_eq!(row.mul_accumulate::<f64>(&col)[(0, 0)], 100_001_000.0);
+
+        // integer widening sidesteps overflow
+        let a = Matrix::from([[100i8,100],[-100,100]]);
+        assert_eq!(a.mul_accumulate::<i32>(&a), Matrix::from([[0,20000],[-20000,0]]));
+
+        let b = Matrix::from([[1.5f32,2.0],[0.25,-1.0]]);
+        assert_eq!(b.mul_accumulate::<f64>(&b), (&b * &b).cast::<f64>());
+    }
+}
@@ -0,0 +1,237 @@
+use crate::Matrix;
+
+// Arithmetic modulo p for u64 entries. Products go through u128 so that any
+// modulus below 2^64 is safe; p is assumed prime wherever division is needed.
+
+pub(crate) fn mul_mod(a : u64, b : u64, p : u64) -> u64 {
+    ((a as u128 * b as u128) % p as u128) as u64
+}
+
+fn add_mod(a : u64, b : u64, p : u64) -> u64 {
+    ((a as u128 + b as u128) % p as u128) as u64
+}
+
+fn sub_mod(a : u64, b : u64, p : u64) -> u64 {
+    add_mod(a, p - b, p)
+}
+
+pub(crate) fn pow_mod(mut base : u64, mut e : u64, p : u64) -> u64 {
+    let mut result = 1 % p;
+    base %= p;
+    while e > 0 {
+        if e & 1 == 1 {
+            result = mul_mod(result, base, p);
+        }
+        base = mul_mod(base, base, p);
+        e >>= 1;
+    }
+    result
+}
+
+// Multiplicative inverse by Fermat's little theorem
+pub(crate) fn inv_mod(a : u64, p : u64) -> u64 {
+    pow_mod(a, p - 2, p)
+}
+
+// Result of reducing rows to reduced row echelon form mod p
+pub(crate) struct ModularReduction {
+    pub(crate) pivots : Vec<usize>,
+    // determinant of the leading square block, if every one of its columns
+    // got a pivot (and zero otherwise)
+    pub(crate) determinant : u64,
+}
+
+// Gauss-Jordan elimination mod p over the rows, only choosing pivots from
+// the first `pivot_cols` columns (the rest are carried along, as for an
+// augmented matrix)
+pub(crate) fn reduce_mod(rows : &mut [Vec<u64>], pivot_cols : usize, p : u64) -> ModularReduction {
+    for row in rows.iter_mut() {
+        row.iter_mut().for_each(|x| *x %= p);
+    }
+
+    let mut pivots = Vec::new();
+    let mut determinant = 1 % p;
+
+    for col in 0..pivot_cols {
+        let r = pivots.len();
+        let pivot = match (r..rows.len()).find(|&i| rows[i][col] != 0) {
+            Some(pivot) => pivot,
+            None => {
+                determinant = 0;
+                continue;
+            }
+        };
+        if pivot != r {
+            rows.swap(pivot, r);
+            determinant = sub_mod(0, determinant, p);
+        }
+
+        let value = rows[r][col];
+        determinant = mul_mod(determinant, value, p);
+        let inverse = inv_mod(value, p);
+        rows[r].iter_mut().for_each(|x| *x = mul_mod(*x, inverse, p));
+
+        for i in 0..rows.len() {
+            let factor = rows[i][col];
+            if i == r || factor == 0 {
+                continue;
+            }
+            for j in 0..rows[i].len() {
+                let t = mul_mod(factor, rows[r][j], p);
+                rows[i][j] = sub_mod(rows[i][j], t, p);
+            }
+        }
+        pivots.push(col);
+    }
+
+    ModularReduction { pivots, determinant }
+}
+
+impl Matrix<u64> {
+    // Entries reduced into [0, p)
+    pub fn reduce_mod(&self, p : u64) -> Matrix<u64> {
+        assert!(p > 0);
+        Matrix::from_fn(self.rows, self.cols, |i, j| self.m[i][j] % p)
+    }
+
+    pub fn mul_mod(&self, rhs : &Matrix<u64>, p : u64) -> Matrix<u64> {
+        // can only multiply if LHS.cols == RHS.rows
+        assert!(self.cols == rhs.rows);
+        assert!(p > 0);
+
+        Matrix::from_fn(self.rows, rhs.cols, |i, j| {
+            (0..self.cols).fold(0, |acc, k| add_mod(acc, mul_mod(self.m[i][k], rhs.m[k][j], p), p))
+        })
+    }
+
+    // A^e mod p by repeated squaring
+    pub fn pow_mod(&self, mut e : u64, p : u64) -> Matrix<u64> {
+        assert!(self.is_square());
+
+        let mut result = Matrix::<u64>::identity(self.rows).reduce_mod(p);
+        let mut base = self.reduce_mod(p);
+        while e > 0 {
+            if e & 1 == 1 {
+                result = result.mul_mod(&base, p);
+            }
+            base = base.mul_mod(&base, p);
+            e >>= 1;
+        }
+        result
+    }
+
+    // Determinant mod a prime p, by modular elimination
+    pub fn determinant_mod(&self, p : u64) -> u64 {
+        assert!(self.is_square());
+
+        let mut rows = self.m.clone();
+        reduce_mod(&mut rows, self.cols, p).determinant
+    }
+
+    // Inverse mod a prime p, or None if the matrix is singular mod p
+    pub fn inverse_mod(&self, p : u64) -> Option<Matrix<u64>> {
+        assert!(self.is_square());
+
+        let n = self.rows;
+        let mut rows : Vec<Vec<u64>> = self.m.iter().enumerate().map(|(i, row)| {
+            let mut augmented = row.clone();
+            augmented.extend((0..n).map(|j| if i == j { 1 } else { 0 }));
+            augmented
+        }).collect();
+
+        if reduce_mod(&mut rows, n, p).pivots.len() < n {
+            return None;
+        }
+        let m = rows.into_iter().map(|row| row[n..].to_vec()).collect();
+        Some(Matrix { m, rows : n, cols : n })
+    }
+
+    // Rank over GF(p) for a prime p
+    pub fn rank_mod(&self, p : u64) -> usize {
+        let mut rows = self.m.clone();
+        reduce_mod(&mut rows, self.cols, p).pivots.len()
+    }
+
+    // A solution of AX = B over GF(p) for a prime p, where A may be any
+    // shape and B has one column per right hand side, or None if some
+    // column of B is outside the column space. When solutions aren't unique
+    // the free variables are set to zero.
+    pub fn solve_mod(&self, b : &Matrix<u64>, p : u64) -> Option<Matrix<u64>> {
+        assert!(self.rows == b.rows);
+
+        let n = self.cols;
+        let mut rows = self.augment(b).m;
+        let pivots = reduce_mod(&mut rows, n, p).pivots;
+
+        // rows past the rank have nothing left of A, so must have nothing of B
+        if rows[pivots.len()..].iter().any(|row| row[n..].iter().any(|&x| x != 0)) {
+            return None;
+        }
+
+        let mut x = Matrix::new(n, b.cols);
+        for (row, &col) in rows.iter().zip(pivots.iter()) {
+            x.m[col].copy_from_slice(&row[n..]);
+        }
+        Some(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Matrix;
+
+    const P : u64 = 1_000_000_007;
+
+    #[test]
+    fn fibonacci_by_matrix_power() {
+        let f = Matrix::from([[1u64,1],[1,0]]);
+
+        assert_eq!(f.pow_mod(10, P)[(0, 1)], 55);
+        // F(1000) mod 1e9+7
+        assert_eq!(f.pow_mod(1000, P)[(0, 1)], 517_691_607);
+        assert!(f.pow_mod(0, P).is_identity(0));
+    }
+
+    #[test]
+    fn modular_inverse() {
+        let a = Matrix::from([[2u64,3,1],[4,1,5],[7,0,6]]);
+        let inv = a.inverse_mod(P).unwrap();
+        assert!(a.mul_mod(&inv, P).is_identity(0));
+
+        // singular mod 7 but not over the integers
+        let b = Matrix::from([[1u64,2],[3,13]]);
+        assert_eq!(b.determinant_mod(7), 0);
+        assert_eq!(b.inverse_mod(7), None);
+        assert_eq!(b.determinant_mod(P), 7);
+    }
+
+    #[test]
+    fn modular_products_do_not_overflow() {
+        let big = u64::MAX - 58; // the largest prime below 2^64
+        let a = Matrix::from([[big - 1, big - 2],[3, big - 1]]);
+        let b = a.mul_mod(&a, big);
+
+        // (-1)(-1) + (-2)(3) = -5
+        assert_eq!(b[(0, 0)], big - 5);
+    }
+
+    #[test]
+    fn rank_and_solve_over_finite_field() {
+        // rows 0 and 1 sum to row 2 mod 5, though not over the rationals
+        let a = Matrix::from([[1u64,2,3],[4,1,2],[0,3,0]]);
+        assert_eq!(a.rank_mod(5), 2);
+        assert_eq!(a.rank_mod(7), 3);
+        assert_eq!(a.cast_as::<i64>().determinant(), 30);
+
+        let b = Matrix::from([[1u64],[2],[3]]);
+        let x = a.solve_mod(&b, 5).unwrap();
+        assert_eq!(a.mul_mod(&x, 5), b);
+        assert!(a.solve_mod(&Matrix::from([[1u64],[0],[0]]), 5).is_none());
+
+        // a wide system, with several right hand sides
+        let w = Matrix::from([[1u64,1,0,1],[0,1,1,1]]);
+        let rhs = Matrix::from([[1u64,0],[1,1]]);
+        assert_eq!(w.mul_mod(&w.solve_mod(&rhs, 2).unwrap(), 2), rhs);
+        assert_eq!(w.rank_mod(2), 2);
+    }
+}
@@ -0,0 +1,43 @@
+use nalgebra::{DMatrix, Scalar};
+
+use crate::Matrix;
+
+impl<T : Scalar> From<DMatrix<T>> for Matrix<T> {
+    fn from(other : DMatrix<T>) -> Self {
+        let (rows, cols) = other.shape();
+
+        // nalgebra stores its entries in column-major order, so deal each
+        // entry out to its row rather than cloning through a transpose
+        let data : Vec<T> = other.data.into();
+        let mut m : Vec<Vec<T>> = (0..rows).map(|_| Vec::with_capacity(cols)).collect();
+        for (k, entry) in data.into_iter().enumerate() {
+            m[k % rows].push(entry);
+        }
+
+        Matrix { m, rows, cols }
+    }
+}
+
+impl<T : Scalar> From<Matrix<T>> for DMatrix<T> {
+    fn from(other : Matrix<T>) -> Self {
+        DMatrix::from_row_iterator(other.rows, other.cols, other.m.into_iter().flatten())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::DMatrix;
+
+    use crate::Matrix;
+
+    #[test]
+    fn nalgebra_round_trip() {
+        let m = Matrix::from(vec![vec![1,2,3],vec![4,5,6]]);
+        let n : DMatrix<i32> = m.clone().into();
+
+        assert_eq!(n.shape(), (2, 3));
+        assert_eq!(n[(1, 0)], 4);
+        assert_eq!(n[(0, 2)], 3);
+        assert_eq!(Matrix::from(n), m);
+    }
+}
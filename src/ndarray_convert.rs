@@ -0,0 +1,90 @@
+use ndarray::{Array2, ArrayView1, ArrayView2};
+
+use crate::Matrix;
+
+// Matrix keeps each row in its own Vec, so no layout ever lines up with an
+// Array2 buffer exactly; the owned conversions below move entries across
+// instead of cloning them, and only the borrowed ones need T : Clone. For
+// the same reason there is no ArrayView2 of a Matrix, which would need one
+// strided buffer, but each row is contiguous and can be viewed in place.
+
+impl<T> Matrix<T> {
+    // Row i as a one dimensional ndarray view of its own storage, no copy
+    pub fn row_view(&self, i : usize) -> ArrayView1<'_, T> {
+        ArrayView1::from(&self.m[i][..])
+    }
+}
+
+impl<T> From<Array2<T>> for Matrix<T> {
+    fn from(other : Array2<T>) -> Self {
+        let (rows, cols) = other.dim();
+
+        // into_iter walks the array in logical (row-major) order whatever
+        // its memory layout
+        let mut entries = other.into_iter();
+        let m : Vec<Vec<T>> = (0..rows)
+            .map(|_| entries.by_ref().take(cols).collect())
+            .collect();
+
+        Matrix { m, rows, cols }
+    }
+}
+
+impl<T : Clone> From<ArrayView2<'_, T>> for Matrix<T> {
+    fn from(other : ArrayView2<'_, T>) -> Self {
+        let (rows, cols) = other.dim();
+        let m = other.rows().into_iter().map(|row| row.to_vec()).collect();
+
+        Matrix { m, rows, cols }
+    }
+}
+
+impl<T> From<Matrix<T>> for Array2<T> {
+    fn from(other : Matrix<T>) -> Self {
+        let shape = (other.rows, other.cols);
+        Array2::from_shape_vec(shape, other.into_row_major_vec()).unwrap()
+    }
+}
+
+impl<T : Clone> From<&Matrix<T>> for Array2<T> {
+    fn from(other : &Matrix<T>) -> Self {
+        Array2::from_shape_fn((other.rows, other.cols), |(i, j)| other.m[i][j].clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ndarray::{array, Array2};
+
+    use crate::Matrix;
+
+    #[test]
+    fn ndarray_round_trip() {
+        let m = Matrix::from(vec![vec![1,2,3],vec![4,5,6]]);
+        let a : Array2<i32> = (&m).into();
+
+        assert_eq!(a, array![[1,2,3],[4,5,6]]);
+        assert_eq!(Array2::from(m.clone()), a);
+        assert_eq!(Matrix::from(a), m);
+    }
+
+    #[test]
+    fn ndarray_row_views() {
+        let m = Matrix::from([[1,2,3],[4,5,6]]);
+        let row = m.row_view(1);
+
+        assert_eq!(row, array![4,5,6]);
+        assert_eq!(row.as_ptr(), m.row(1).as_ptr());
+        assert_eq!(m.row_view(0).dot(&row), 32);
+    }
+
+    #[test]
+    fn ndarray_non_standard_layout() {
+        // a transposed array is stored column-major
+        let a = array![[1,2,3],[4,5,6]].reversed_axes();
+        let m = Matrix::from(vec![vec![1,4],vec![2,5],vec![3,6]]);
+
+        assert_eq!(Matrix::from(a.view()), m);
+        assert_eq!(Matrix::from(a), m);
+    }
+}
@@ -0,0 +1,234 @@
+use num_traits::Float;
+
+use crate::svd::{columns, from_columns, orthonormalize_columns};
+use crate::Matrix;
+
+impl<T : Float> Matrix<T> {
+    // Orthonormal basis for the span of the columns by modified
+    // Gram-Schmidt, working through the columns in order. A column which is
+    // numerically dependent on the ones before it is dropped, so the result
+    // has one column per unit of rank.
+    pub fn orthonormalize(&self) -> Matrix<T> {
+        from_columns(&self.orthonormal_columns(), self.rows)
+    }
+
+    fn orthonormal_columns(&self) -> Vec<Vec<T>> {
+        let mut q = columns(self);
+        orthonormalize_columns(&mut q);
+        q
+    }
+
+    // Orthogonal projector P = A(AᵀA)⁻¹Aᵀ onto the column space, formed as
+    // QQᵀ from the orthonormalised columns (the Q of a QR factorisation)
+    // rather than through the normal equations, which square the condition
+    // number. Dependent columns are fine: P still projects onto their span.
+    pub fn projection_onto_columns(&self) -> Matrix<T> {
+        let basis = self.orthonormal_columns();
+        Matrix::from_fn(self.rows, self.rows, |i, j| {
+            basis.iter().fold(T::zero(), |acc, col| acc + col[i] * col[j])
+        })
+    }
+
+    // Closest point to v in the column space, Q(Qᵀv), without forming the
+    // rows × rows projector. v - project(v) is the least squares residual.
+    pub fn project(&self, v : &[T]) -> Vec<T> {
+        assert!(v.len() == self.rows);
+
+        let mut p = vec![T::zero() ; self.rows];
+        for col in self.orthonormal_columns().iter() {
+            let c = col.iter().zip(v.iter()).fold(T::zero(), |acc, (&a, &b)| acc + a * b);
+            for (x, &qi) in p.iter_mut().zip(col.iter()) {
+                *x = *x + c * qi;
+            }
+        }
+        p
+    }
+}
+
+// Householder reflections H = I - 2wwᵀ, w = v / |v|, which mirror
+// through the hyperplane orthogonal to v. H is symmetric and orthogonal,
+// and a zero v is taken to mean H = I.
+impl<T : Float> Matrix<T> {
+    // The dense n × n reflector for a length n vector v
+    pub fn householder(v : &[T]) -> Matrix<T> {
+        let n = v.len();
+        let mut h = Matrix::from_fn(n, n, |i, j| if i == j { T::one() } else { T::zero() });
+        h.apply_householder_left(v);
+        h
+    }
+
+    // 2 / vᵀv, or None for a zero v
+    fn reflector_scale(v : &[T]) -> Option<T> {
+        let norm = v.iter().fold(T::zero(), |acc, &x| acc + x * x);
+        if norm == T::zero() { None } else { Some((T::one() + T::one()) / norm) }
+    }
+
+    // A <- HA in O(rows · cols), where v has one entry per row
+    pub fn apply_householder_left(&mut self, v : &[T]) {
+        assert!(v.len() == self.rows);
+        let beta = match Matrix::reflector_scale(v) {
+            Some(beta) => beta,
+            None => return,
+        };
+
+        // A - β v (vᵀA)
+        let mut vt_a = vec![T::zero() ; self.cols];
+        for (row, &vi) in self.m.iter().zip(v.iter()) {
+            for (s, &a) in vt_a.iter_mut().zip(row.iter()) {
+                *s = *s + vi * a;
+            }
+        }
+        for (row, &vi) in self.m.iter_mut().zip(v.iter()) {
+            for (a, &s) in row.iter_mut().zip(vt_a.iter()) {
+                *a = *a - beta * vi * s;
+            }
+        }
+    }
+
+    // A <- AH in O(rows · cols), where v has one entry per column
+    pub fn apply_householder_right(&mut self, v : &[T]) {
+        assert!(v.len() == self.cols);
+        let beta = match Matrix::reflector_scale(v) {
+            Some(beta) => beta,
+            None => return,
+        };
+
+        // A - β (Av) vᵀ
+        for row in self.m.iter_mut() {
+            let s = row.iter().zip(v.iter()).fold(T::zero(), |acc, (&a, &vi)| acc + a * vi) * beta;
+            for (a, &vi) in row.iter_mut().zip(v.iter()) {
+                *a = *a - s * vi;
+            }
+        }
+    }
+}
+
+// Givens rotations, which act on two coordinates at a time and so can zero
+// one chosen entry while disturbing only two rows (or columns)
+impl<T : Float> Matrix<T> {
+    // (c, s) with c² + s² = 1 and (c a + s b, c b - s a) = (r, 0), where
+    // r = hypot(a, b) is computed without overflow
+    pub fn givens(a : T, b : T) -> (T, T) {
+        if b == T::zero() {
+            return (T::one(), T::zero());
+        }
+        let r = a.hypot(b);
+        (a / r, b / r)
+    }
+
+    // (row i, row k) <- (c row i + s row k, c row k - s row i), i.e. GA for
+    // the rotation G in the (i, k) plane. With (c, s) = givens(A[i][j],
+    // A[k][j]) this zeroes A[k][j].
+    pub fn rotate_rows(&mut self, i : usize, k : usize, c : T, s : T) {
+        assert!(i != k && i < self.rows && k < self.rows);
+
+        for j in 0..self.cols {
+            let (x, y) = (self.m[i][j], self.m[k][j]);
+            self.m[i][j] = c * x + s * y;
+            self.m[k][j] = c * y - s * x;
+        }
+    }
+
+    // The same rotation on (column i, column k), i.e. AGᵀ. With
+    // (c, s) = givens(A[j][i], A[j][k]) this zeroes A[j][k].
+    pub fn rotate_cols(&mut self, i : usize, k : usize, c : T, s : T) {
+        assert!(i != k && i < self.cols && k < self.cols);
+
+        for row in self.m.iter_mut() {
+            let (x, y) = (row[i], row[k]);
+            row[i] = c * x + s * y;
+            row[k] = c * y - s * x;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Matrix;
+
+    #[test]
+    fn gram_schmidt() {
+        let a = Matrix::from([[1.0f64,1.0,0.0],[1.0,0.0,1.0],[0.0,1.0,1.0]]);
+        let q = a.orthonormalize();
+        assert!(q.is_orthogonal(1e-12));
+
+        // the first column only gets normalised
+        let s = 0.5f64.sqrt();
+        assert!((q[(0, 0)] - s).abs() < 1e-15 && (q[(1, 0)] - s).abs() < 1e-15);
+
+        // the middle column is the sum of the others, so the last one adds
+        // nothing new and is dropped
+        let b = Matrix::from([[1.0,3.0,2.0],[0.0,1.0,1.0],[1.0,1.0,0.0],[2.0,2.0,0.0]]);
+        let q = b.orthonormalize();
+        assert_eq!((q.rows(), q.cols()), (4, 2));
+        assert!(q.gram().is_identity(1e-12));
+    }
+
+    #[test]
+    fn projections() {
+        // the plane z = x + y in R³, spanned by dependent columns
+        let a = Matrix::from([[1.0f64,0.0,2.0],[0.0,1.0,1.0],[1.0,1.0,3.0]]);
+        let p = a.projection_onto_columns();
+
+        assert!(p.is_symmetric(1e-12));
+        assert!((&(&p * &p) - &p).entries_vanish(&1e-12, |_, _| true));
+        assert!(p.mul_vec(&[1.0,1.0,2.0]).iter().zip([1.0,1.0,2.0].iter()).all(|(x, y)| (x - y).abs() < 1e-12));
+
+        // (1, 1, -1) is normal to the plane, so the residual is along it
+        let v = [1.0,2.0,0.0];
+        let projected = a.project(&v);
+        let residual : Vec<f64> = v.iter().zip(projected.iter()).map(|(x, y)| x - y).collect();
+        assert!((residual[0] - 1.0).abs() < 1e-12);
+        assert!((residual[1] - 1.0).abs() < 1e-12);
+        assert!((residual[2] + 1.0).abs() < 1e-12);
+        assert!(p.mul_vec(&v).iter().zip(projected.iter()).all(|(x, y)| (x - y).abs() < 1e-12));
+    }
+
+    #[test]
+    fn householder_reflections() {
+        // v = x - |x| e1 takes x onto |x| e1
+        let x = [3.0f64,4.0,0.0];
+        let v = [3.0 - 5.0,4.0,0.0];
+        let h = Matrix::householder(&v);
+
+        assert!(h.is_orthogonal(1e-12) && h.is_symmetric(1e-12));
+        let hx = h.mul_vec(&x);
+        assert!((hx[0] - 5.0).abs() < 1e-12 && hx[1].abs() < 1e-12);
+
+        let a = Matrix::from([[1.0,2.0],[3.0,4.0],[5.0,6.0]]);
+        let mut left = a.clone();
+        left.apply_householder_left(&v);
+        assert!((&left - &(&h * &a)).entries_vanish(&1e-12, |_, _| true));
+
+        let mut b = a.clone();
+        b.transpose();
+        b.apply_householder_right(&v);
+        left.transpose();
+        assert!((&b - &left).entries_vanish(&1e-12, |_, _| true));
+
+        assert!(Matrix::householder(&[0.0,0.0]).is_identity(0.0));
+    }
+
+    #[test]
+    fn givens_rotations() {
+        let (c, s) = Matrix::givens(3.0f64, 4.0);
+        assert!((c - 0.6).abs() < 1e-15 && (s - 0.8).abs() < 1e-15);
+        assert_eq!(Matrix::givens(2.0f64, 0.0), (1.0, 0.0));
+
+        // zero the sub-diagonal of a 3×2 matrix one entry at a time: a QR
+        let mut r = Matrix::from([[1.0f64,2.0],[3.0,4.0],[5.0,6.0]]);
+        for (j, k) in [(0, 1), (0, 2), (1, 2)].iter().cloned() {
+            let (c, s) = Matrix::givens(r[(j, j)], r[(k, j)]);
+            r.rotate_rows(j, k, c, s);
+            assert!(r[(k, j)].abs() < 1e-12);
+        }
+        assert!(r.is_upper_triangular(1e-12));
+        assert!((r.gram()[(1, 1)] - 56.0).abs() < 1e-12);
+
+        let mut a = Matrix::from([[1.0f64,1.0],[2.0,-1.0]]);
+        let (c, s) = Matrix::givens(a[(1, 0)], a[(1, 1)]);
+        a.rotate_cols(0, 1, c, s);
+        assert!(a[(1, 1)].abs() < 1e-15);
+        assert!((a[(1, 0)] - 5.0f64.sqrt()).abs() < 1e-15);
+    }
+}
@@ -0,0 +1,111 @@
+use num_traits::Float;
+
+use crate::Matrix;
+
+// Principal component analysis of a data matrix with one observation per
+// row. The principal axes are the columns of `components`, in decreasing
+// order of the variance they explain.
+#[derive(Clone,Debug,PartialEq)]
+pub struct Pca<T> {
+    pub mean : Vec<T>,
+    pub components : Matrix<T>,
+    pub explained_variance : Vec<T>,
+    pub explained_variance_ratio : Vec<T>,
+}
+
+impl<T : Float> Pca<T> {
+    // Centres new observations (one per row) with the training mean and
+    // expresses them in terms of the principal axes
+    pub fn transform(&self, data : &Matrix<T>) -> Matrix<T> {
+        assert!(data.cols == self.mean.len());
+
+        let m = data.m.iter().map(|row| {
+            (0..self.components.cols).map(|k| {
+                row.iter().zip(self.mean.iter()).enumerate().fold(T::zero(), |acc, (j, (&x, &mu))| {
+                    acc + (x - mu) * self.components.m[j][k]
+                })
+            }).collect()
+        }).collect();
+
+        Matrix { m, rows : data.rows, cols : self.components.cols }
+    }
+}
+
+impl<T : Float> Matrix<T> {
+    pub fn column_means(&self) -> Vec<T> {
+        let count = T::from(self.rows).unwrap();
+        (0..self.cols)
+            .map(|j| self.m.iter().fold(T::zero(), |acc, row| acc + row[j]) / count)
+            .collect()
+    }
+
+    // Sample covariance of the columns, treating each row as an observation
+    pub fn covariance(&self) -> Matrix<T> {
+        assert!(self.rows > 1);
+
+        let mean = self.column_means();
+        let scale = T::from(self.rows - 1).unwrap();
+        Matrix::from_fn(self.cols, self.cols, |i, j| {
+            self.m.iter().fold(T::zero(), |acc, row| acc + (row[i] - mean[i]) * (row[j] - mean[j])) / scale
+        })
+    }
+
+    // Keeps the n_components leading eigenvectors of the covariance matrix
+    pub fn pca(&self, n_components : usize) -> Pca<T> {
+        assert!(n_components <= self.cols);
+
+        let eigen = self.covariance().symmetric_eigen();
+        let total = eigen.eigenvalues.iter().fold(T::zero(), |acc, &l| acc + l);
+
+        let explained_variance : Vec<T> = eigen.eigenvalues[..n_components].to_vec();
+        let explained_variance_ratio = explained_variance.iter().map(|&l| l / total).collect();
+        let m = eigen.eigenvectors.m.iter().map(|row| row[..n_components].to_vec()).collect();
+
+        Pca {
+            mean : self.column_means(),
+            components : Matrix { m, rows : self.cols, cols : n_components },
+            explained_variance,
+            explained_variance_ratio,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Matrix;
+
+    #[test]
+    fn basic_covariance() {
+        let data = Matrix::from([[1.0f64,2.0],[2.0,4.0],[3.0,6.0],[4.0,8.0]]);
+        let c = data.covariance();
+
+        assert_eq!(data.column_means(), vec![2.5, 5.0]);
+        assert!(c.is_symmetric(0.0));
+        assert!((c[(0, 0)] - 5.0 / 3.0).abs() < 1e-12);
+        assert!((c[(0, 1)] - 10.0 / 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn basic_pca() {
+        // points spread along (2, 1) with a little perpendicular scatter,
+        // arranged so that it is uncorrelated with the position along the line
+        let data = Matrix::from((0..20).map(|i| {
+            let t = i as f64 - 9.5;
+            let e = if i % 4 == 0 || i % 4 == 3 { 0.1 } else { -0.1 };
+            vec![1.0 + 2.0 * t - e, -3.0 + t + 2.0 * e]
+        }).collect::<Vec<_>>());
+
+        let pca = data.pca(1);
+        let axis = (pca.components[(0, 0)], pca.components[(1, 0)]);
+        let expected = (2.0 / 5f64.sqrt(), 1.0 / 5f64.sqrt());
+        assert!((axis.0.abs() - expected.0).abs() < 1e-6);
+        assert!((axis.1.abs() - expected.1).abs() < 1e-6);
+        assert!(pca.explained_variance_ratio[0] > 0.999);
+
+        // the projected training data has the explained variance
+        let scores = pca.transform(&data);
+        assert_eq!((scores.rows(), scores.cols()), (20, 1));
+        let variance = scores.covariance()[(0, 0)];
+        assert!((variance - pca.explained_variance[0]).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,176 @@
+use std::ops::{Add, AddAssign, Div, Mul, Sub};
+
+use num_traits::{Float, One};
+
+use crate::Matrix;
+
+// Polynomials are returned as coefficient vectors in increasing powers of x,
+// so p[k] is the coefficient of x^k
+
+impl<T> Matrix<T>
+    where T : AddAssign+Clone+Default+One+Add<Output=T>+Sub<Output=T>+Mul<Output=T>+Div<Output=T> {
+    // Characteristic polynomial det(xI - A) by the Faddeev-LeVerrier
+    // algorithm. Every division is exact for integer matrices, so this is
+    // suitable for integers and rationals; for floats prefer
+    // char_poly_hessenberg.
+    pub fn char_poly(&self) -> Vec<T> {
+        assert!(self.is_square());
+
+        let n = self.rows;
+        let mut coeffs = vec![T::default() ; n + 1];
+        coeffs[n] = T::one();
+
+        // M_k = A M_{k-1} + c_{n-k+1} I, starting from M_0 = 0
+        let mut m = Matrix::<T>::new(n, n);
+        let mut k_value = T::default();
+        for k in 1..=n {
+            k_value += T::one();
+            let mut next = self * &m;
+            for i in 0..n {
+                next.m[i][i] += coeffs[n - k + 1].clone();
+            }
+
+            let am = self * &next;
+            let mut trace = T::default();
+            for i in 0..n {
+                trace += am.m[i][i].clone();
+            }
+            coeffs[n - k] = T::default() - trace / k_value.clone();
+            m = next;
+        }
+
+        coeffs
+    }
+}
+
+impl<T> Matrix<T>
+    where T : AddAssign+Clone+Default+One+PartialEq+Add<Output=T>+Sub<Output=T>+Mul<Output=T>+Div<Output=T> {
+    // Minimal polynomial (monic) for matrices over exact fields such as the
+    // rationals. Walks the Krylov sequence I, A, A², ... and stops at the
+    // first power which is a linear combination of the earlier ones; exact
+    // zero tests make this unsuitable for floats.
+    pub fn min_poly(&self) -> Vec<T> {
+        assert!(self.is_square());
+
+        let n = self.rows;
+        let zero = T::default();
+
+        // reduced basis vectors, the position of each one's pivot, and which
+        // combination of powers of A each one is
+        let mut basis : Vec<(Vec<T>, usize, Vec<T>)> = Vec::new();
+        let mut power = Matrix::<T>::identity(n);
+
+        for k in 0..=n {
+            let mut w : Vec<T> = power.m.iter().flatten().cloned().collect();
+            let mut combination = vec![zero.clone() ; k + 1];
+            combination[k] = T::one();
+
+            for (b, pivot, coeffs) in basis.iter() {
+                if w[*pivot] == zero {
+                    continue;
+                }
+                let factor = w[*pivot].clone() / b[*pivot].clone();
+                for (x, y) in w.iter_mut().zip(b.iter()) {
+                    *x = x.clone() - factor.clone() * y.clone();
+                }
+                for (x, y) in combination.iter_mut().zip(coeffs.iter()) {
+                    *x = x.clone() - factor.clone() * y.clone();
+                }
+            }
+
+            match w.iter().position(|x| *x != zero) {
+                Some(pivot) => basis.push((w, pivot, combination)),
+                // Σ combination_j A^j = 0, and the x^k coefficient is 1
+                None => return combination,
+            }
+            power = &power * self;
+        }
+
+        unreachable!("Cayley-Hamilton bounds the degree by n")
+    }
+}
+
+impl<T : Float> Matrix<T> {
+    // Characteristic polynomial det(xI - A) for float matrices, by reducing
+    // to Hessenberg form (a similarity, so the polynomial is unchanged) and
+    // then using the recurrence for the leading principal minors
+    pub fn char_poly_hessenberg(&self) -> Vec<T> {
+        let (h, _) = self.hessenberg();
+        let n = h.rows;
+
+        // p[k] is the characteristic polynomial of the leading k×k block
+        let mut p : Vec<Vec<T>> = vec![vec![T::one()]];
+        for k in 0..n {
+            // (x - h_kk) p_k
+            let mut next = vec![T::zero() ; k + 2];
+            for (d, &c) in p[k].iter().enumerate() {
+                next[d + 1] = next[d + 1] + c;
+                next[d] = next[d] - h.m[k][k] * c;
+            }
+
+            // minus h_ik times the product of the sub-diagonal between rows
+            // i and k, times p_i
+            let mut product = T::one();
+            for i in (0..k).rev() {
+                product = product * h.m[i + 1][i];
+                let scale = h.m[i][k] * product;
+                for (d, &c) in p[i].iter().enumerate() {
+                    next[d] = next[d] - scale * c;
+                }
+            }
+            p.push(next);
+        }
+
+        p.pop().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_rational::Ratio;
+
+    use crate::Matrix;
+
+    #[test]
+    fn integer_char_poly() {
+        // x^2 - 5x - 2
+        assert_eq!(Matrix::from([[1,2],[3,4]]).char_poly(), vec![-2,-5,1]);
+
+        let a = Matrix::from([[2,-1,0,3],[1,1,4,0],[0,2,-3,1],[5,0,1,2]]);
+        let p = a.char_poly();
+        assert_eq!(p.len(), 5);
+        // constant term is det(-A) = det(A) for even n, and x^3 is -trace
+        assert_eq!(p[0], a.determinant());
+        assert_eq!(p[3], -2);
+    }
+
+    #[test]
+    fn float_char_poly() {
+        let a = Matrix::from([[2.0,-1.0,0.0,3.0],[1.0,1.0,4.0,0.0],[0.0,2.0,-3.0,1.0],[5.0,0.0,1.0,2.0]]);
+        let exact = Matrix::from([[2,-1,0,3],[1,1,4,0],[0,2,-3,1],[5,0,1,2]]).char_poly();
+        let p = a.char_poly_hessenberg();
+
+        for (c, e) in p.iter().zip(exact.iter()) {
+            assert!((c - *e as f64).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn basic_min_poly() {
+        let q = |n : i64, d : i64| Ratio::new(n, d);
+        let rational = |m : Vec<Vec<Ratio<i64>>>| Matrix::from(m);
+
+        // repeated eigenvalue but diagonalisable, and not diagonal:
+        // (x - 2)(x - 3), a proper factor of (x - 2)²(x - 3)
+        let a = rational(vec![vec![q(2,1),q(0,1),q(1,2)],vec![q(0,1),q(2,1),q(0,1)],vec![q(0,1),q(0,1),q(3,1)]]);
+        assert_eq!(a.min_poly(), vec![q(6,1),q(-5,1),q(1,1)]);
+        assert_eq!(a.char_poly(), vec![q(-12,1),q(16,1),q(-7,1),q(1,1)]);
+
+        // a Jordan block needs the full (x - 1/3)²
+        let j = rational(vec![vec![q(1,3),q(1,1)],vec![q(0,1),q(1,3)]]);
+        assert_eq!(j.min_poly(), vec![q(1,9),q(-2,3),q(1,1)]);
+        assert_eq!(j.min_poly(), j.char_poly());
+
+        assert_eq!(Matrix::<Ratio<i64>>::identity(4).min_poly(), vec![q(-1,1),q(1,1)]);
+    }
+}
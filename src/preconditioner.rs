@@ -0,0 +1,213 @@
+use num_traits::Float;
+
+use crate::{RowEntries, SparseMatrix};
+
+// An approximation M⁻¹ of the inverse of a system matrix, applied to a
+// residual r to give z = M⁻¹r. Cheap to apply and close to A⁻¹ is the aim.
+pub trait Preconditioner<T> {
+    fn apply(&self, r : &[T]) -> Vec<T>;
+}
+
+// Does nothing; this is what the unpreconditioned solvers use
+#[derive(Clone,Copy,Debug,Default,PartialEq,Eq)]
+pub struct IdentityPreconditioner;
+
+impl<T : Clone> Preconditioner<T> for IdentityPreconditioner {
+    fn apply(&self, r : &[T]) -> Vec<T> {
+        r.to_vec()
+    }
+}
+
+// Divides by the diagonal of A
+#[derive(Clone,Debug,PartialEq)]
+pub struct JacobiPreconditioner<T> {
+    inverse_diagonal : Vec<T>,
+}
+
+impl<T : Float> JacobiPreconditioner<T> {
+    // Returns None if A has a zero on its diagonal
+    pub fn new<A : RowEntries<T>>(a : &A) -> Option<Self> {
+        assert!(a.rows() == a.cols());
+
+        let mut inverse_diagonal = vec![T::zero() ; a.rows()];
+        for (i, d) in inverse_diagonal.iter_mut().enumerate() {
+            a.for_each_in_row(i, |j, &v| {
+                if i == j {
+                    *d = v;
+                }
+            });
+            if *d == T::zero() {
+                return None;
+            }
+            *d = d.recip();
+        }
+
+        Some(JacobiPreconditioner { inverse_diagonal })
+    }
+}
+
+impl<T : Float> Preconditioner<T> for JacobiPreconditioner<T> {
+    fn apply(&self, r : &[T]) -> Vec<T> {
+        r.iter().zip(self.inverse_diagonal.iter()).map(|(&ri, &d)| ri * d).collect()
+    }
+}
+
+// Incomplete LU factorisation with zero fill-in: L and U are restricted to
+// the sparsity pattern of A, and stored together in a copy of it (the unit
+// diagonal of L is implicit)
+#[derive(Clone,Debug,PartialEq)]
+pub struct Ilu0<T> {
+    lu : SparseMatrix<T>,
+    diagonal : Vec<usize>,
+}
+
+impl<T : Float> Ilu0<T> {
+    // Returns None if a diagonal entry of A is missing from its sparsity
+    // pattern or a zero pivot turns up
+    pub fn new(a : &SparseMatrix<T>) -> Option<Self> {
+        assert!(a.rows == a.cols);
+
+        let n = a.rows;
+        let mut lu = a.clone();
+
+        // position of each diagonal entry within the value array
+        let mut diagonal = Vec::with_capacity(n);
+        for i in 0..n {
+            let row = lu.row_ptr[i]..lu.row_ptr[i + 1];
+            let k = lu.col_idx[row.clone()].binary_search(&i).ok()?;
+            diagonal.push(row.start + k);
+        }
+
+        // maps a column to its position in the current row, if present
+        let mut position = vec![None ; n];
+
+        for i in 0..n {
+            let row = lu.row_ptr[i]..lu.row_ptr[i + 1];
+            for k in row.clone() {
+                position[lu.col_idx[k]] = Some(k);
+            }
+
+            // columns are sorted, so the strictly lower part comes first
+            for ik in row.start..diagonal[i] {
+                let k = lu.col_idx[ik];
+                let pivot = lu.values[diagonal[k]];
+                if pivot == T::zero() {
+                    return None;
+                }
+                let factor = lu.values[ik] / pivot;
+                lu.values[ik] = factor;
+
+                for kj in (diagonal[k] + 1)..lu.row_ptr[k + 1] {
+                    if let Some(ij) = position[lu.col_idx[kj]] {
+                        lu.values[ij] = lu.values[ij] - factor * lu.values[kj];
+                    }
+                }
+            }
+
+            for k in row {
+                position[lu.col_idx[k]] = None;
+            }
+            if lu.values[diagonal[i]] == T::zero() {
+                return None;
+            }
+        }
+
+        Some(Ilu0 { lu, diagonal })
+    }
+}
+
+impl<T : Float> Preconditioner<T> for Ilu0<T> {
+    fn apply(&self, r : &[T]) -> Vec<T> {
+        let n = self.diagonal.len();
+        let lu = &self.lu;
+        let mut z = r.to_vec();
+
+        for i in 0..n {
+            for k in lu.row_ptr[i]..self.diagonal[i] {
+                z[i] = z[i] - lu.values[k] * z[lu.col_idx[k]];
+            }
+        }
+        for i in (0..n).rev() {
+            for k in (self.diagonal[i] + 1)..lu.row_ptr[i + 1] {
+                z[i] = z[i] - lu.values[k] * z[lu.col_idx[k]];
+            }
+            z[i] = z[i] / lu.values[self.diagonal[i]];
+        }
+
+        z
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Ilu0, JacobiPreconditioner, Preconditioner};
+    use crate::{cg, cg_preconditioned, gmres, gmres_preconditioned, CooBuilder, SparseMatrix};
+
+    // 2-D Poisson on a k×k grid, plus a convection term to make it nonsymmetric
+    fn grid(k : usize, convection : f64) -> SparseMatrix<f64> {
+        let mut builder = CooBuilder::new(k * k, k * k);
+        for x in 0..k {
+            for y in 0..k {
+                let i = x * k + y;
+                builder.push(i, i, 4.0);
+                if x > 0 { builder.push(i, i - k, -1.0 - convection); }
+                if x + 1 < k { builder.push(i, i + k, -1.0 + convection); }
+                if y > 0 { builder.push(i, i - 1, -1.0); }
+                if y + 1 < k { builder.push(i, i + 1, -1.0); }
+            }
+        }
+        builder.build()
+    }
+
+    #[test]
+    fn jacobi_preconditioned_cg() {
+        // badly scaled SPD system D A D
+        let n = 30;
+        let mut builder = CooBuilder::new(n, n);
+        let scale : Vec<f64> = (0..n).map(|i| 1.0 + (i * i) as f64).collect();
+        for i in 0..n {
+            builder.push(i, i, 2.0 * scale[i] * scale[i]);
+            if i > 0 {
+                builder.push(i, i - 1, -scale[i] * scale[i - 1]);
+                builder.push(i - 1, i, -scale[i] * scale[i - 1]);
+            }
+        }
+        let a = builder.build();
+        let b = vec![1.0 ; n];
+        let x0 = vec![0.0 ; n];
+
+        let m = JacobiPreconditioner::new(&a).unwrap();
+        let plain = cg(&a, &b, &x0, 1e-10, 1000);
+        let preconditioned = cg_preconditioned(&a, &m, &b, &x0, 1e-10, 1000);
+
+        assert!(preconditioned.converged);
+        assert!(preconditioned.iterations < plain.iterations);
+    }
+
+    #[test]
+    fn ilu0_preconditioned_gmres() {
+        let a = grid(10, 0.3);
+        let b = vec![1.0 ; 100];
+        let x0 = vec![0.0 ; 100];
+
+        let m = Ilu0::new(&a).unwrap();
+        let plain = gmres(&a, &b, &x0, 20, 1e-10, 1000);
+        let preconditioned = gmres_preconditioned(&a, &m, &b, &x0, 20, 1e-10, 1000);
+
+        assert!(plain.converged && preconditioned.converged);
+        assert!(preconditioned.iterations < plain.iterations);
+    }
+
+    #[test]
+    fn ilu0_is_exact_without_fill_in() {
+        // a tridiagonal LU has no fill-in, so ILU(0) is the exact inverse
+        let t = SparseMatrix::from_triplets(4, 4, vec![
+            (0,0,2.0),(0,1,-1.0),(1,0,-1.0),(1,1,2.0),(1,2,-1.0),
+            (2,1,-1.0),(2,2,2.0),(2,3,-1.0),(3,2,-1.0),(3,3,2.0)]);
+        let b = [1.0,0.0,0.0,1.0];
+        let z = Ilu0::new(&t).unwrap().apply(&b);
+        let r = t.mul_vec(&z);
+
+        assert!(r.iter().zip(b.iter()).all(|(x, y) : (&f64, &f64)| (x - y).abs() < 1e-12));
+    }
+}
@@ -0,0 +1,128 @@
+use num_traits::Float;
+
+use crate::Matrix;
+
+// Householder QR with column pivoting, AP = QR: Q is orthogonal (rows ×
+// rows), R is upper triangular with non-increasing |R[k][k]|, and column k
+// of AP is column permutation[k] of A. The leading diagonal of R reveals
+// the numerical rank, where plain QR can hide a dependency anywhere.
+#[derive(Clone,Debug,PartialEq)]
+pub struct PivotedQr<T> {
+    pub q : Matrix<T>,
+    pub r : Matrix<T>,
+    pub permutation : Vec<usize>,
+}
+
+impl<T : Float> Matrix<T> {
+    pub fn qr_pivoted(&self) -> PivotedQr<T> {
+        let (m, n) = (self.rows, self.cols);
+        let mut r = self.clone();
+        let mut q = Matrix::from_fn(m, m, |i, j| if i == j { T::one() } else { T::zero() });
+        let mut permutation : Vec<usize> = (0..n).collect();
+
+        for k in 0..m.min(n) {
+            // bring the column with the largest remaining norm to position k
+            let norms : Vec<T> = (k..n).map(|j| (k..m).fold(T::zero(), |acc, i| acc + r.m[i][j] * r.m[i][j])).collect();
+            let pivot = k + (0..norms.len()).fold(0, |best, j| if norms[j] > norms[best] { j } else { best });
+            if pivot != k {
+                for row in r.m.iter_mut() {
+                    row.swap(k, pivot);
+                }
+                permutation.swap(k, pivot);
+            }
+
+            // reflector taking r[k.., k] onto a multiple of e1
+            let mut v : Vec<T> = (0..m).map(|i| if i >= k { r.m[i][k] } else { T::zero() }).collect();
+            let norm = norms[pivot - k].sqrt();
+            if norm == T::zero() {
+                break;
+            }
+            let alpha = if v[k] > T::zero() { -norm } else { norm };
+            v[k] = v[k] - alpha;
+
+            r.apply_householder_left(&v);
+            q.apply_householder_right(&v);
+
+            // these are zero up to rounding
+            r.m[k][k] = alpha;
+            for i in (k + 1)..m {
+                r.m[i][k] = T::zero();
+            }
+        }
+
+        PivotedQr { q, r, permutation }
+    }
+}
+
+impl<T : Float> PivotedQr<T> {
+    // Number of diagonal entries of R above tol relative to the largest
+    pub fn rank(&self, tol : T) -> usize {
+        let k = self.r.rows.min(self.r.cols);
+        let largest = if k == 0 { T::zero() } else { self.r.m[0][0].abs() };
+        (0..k).take_while(|&i| self.r.m[i][i].abs() > tol * largest).count()
+    }
+
+    // Basic least squares solution of Ax ≈ b: the dependent columns beyond
+    // rank(tol) get zero weight, and the rest are solved with the leading
+    // triangle of R. Exact for consistent systems, whatever the rank.
+    pub fn solve(&self, b : &[T], tol : T) -> Vec<T> {
+        assert!(b.len() == self.q.rows);
+
+        let rank = self.rank(tol);
+        let y : Vec<T> = (0..rank)
+            .map(|k| self.q.m.iter().zip(b.iter()).fold(T::zero(), |acc, (row, &bi)| acc + row[k] * bi))
+            .collect();
+
+        let mut z = vec![T::zero() ; rank];
+        for k in (0..rank).rev() {
+            let s = ((k + 1)..rank).fold(y[k], |acc, j| acc - self.r.m[k][j] * z[j]);
+            z[k] = s / self.r.m[k][k];
+        }
+
+        let mut x = vec![T::zero() ; self.r.cols];
+        for (k, zk) in z.into_iter().enumerate() {
+            x[self.permutation[k]] = zk;
+        }
+        x
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Matrix;
+
+    #[test]
+    fn pivoted_qr_factors() {
+        let a = Matrix::from([[1.0f64,2.0,0.0],[0.0,4.0,1.0],[3.0,1.0,-2.0],[1.0,0.0,5.0]]);
+        let qr = a.qr_pivoted();
+
+        assert!(qr.q.is_orthogonal(1e-12));
+        assert!(qr.r.is_upper_triangular(0.0));
+        for k in 1..3 {
+            assert!(qr.r[(k, k)].abs() <= qr.r[(k - 1, k - 1)].abs());
+        }
+
+        let product = &qr.q * &qr.r;
+        for (k, &j) in qr.permutation.iter().enumerate() {
+            for i in 0..4 {
+                assert!((product[(i, k)] - a[(i, j)]).abs() < 1e-12);
+            }
+        }
+        assert_eq!(qr.rank(1e-12), 3);
+    }
+
+    #[test]
+    fn rank_deficient_least_squares() {
+        // column 3 = column 1 + column 2, and b lies in the column space
+        let a = Matrix::from([[1.0f64,0.0,1.0],[0.0,1.0,1.0],[1.0,1.0,2.0],[2.0,-1.0,1.0]]);
+        let qr = a.qr_pivoted();
+        assert_eq!(qr.rank(1e-10), 2);
+
+        let b = a.mul_vec(&[1.0,2.0,0.0]);
+        let x = qr.solve(&b, 1e-10);
+        assert_eq!(x.iter().filter(|&&v| v == 0.0).count(), 1);
+        assert!(a.mul_vec(&x).iter().zip(b.iter()).all(|(p, q)| (p - q).abs() < 1e-12));
+
+        assert_eq!(Matrix::from([[0.0f64,0.0],[0.0,0.0]]).qr_pivoted().rank(1e-10), 0);
+    }
+}
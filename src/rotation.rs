@@ -0,0 +1,173 @@
+use num_traits::Float;
+
+use crate::Matrix;
+
+// Unit quaternions w + xi + yj + zk and the 3×3 rotation matrices they
+// stand for. q and -q give the same rotation; to_quaternion picks w ≥ 0.
+impl<T : Float> Matrix<T> {
+    // Rotation matrix of the quaternion, which is normalised first so that
+    // slightly drifted orientations still give an orthogonal matrix. Panics
+    // on the zero quaternion.
+    pub fn from_quaternion(w : T, x : T, y : T, z : T) -> Matrix<T> {
+        let norm = (w * w + x * x + y * y + z * z).sqrt();
+        assert!(norm > T::zero());
+        let (w, x, y, z) = (w / norm, x / norm, y / norm, z / norm);
+
+        let one = T::one();
+        let two = one + one;
+        Matrix::from([
+            [one - two * (y * y + z * z), two * (x * y - w * z), two * (x * z + w * y)],
+            [two * (x * y + w * z), one - two * (x * x + z * z), two * (y * z - w * x)],
+            [two * (x * z - w * y), two * (y * z + w * x), one - two * (x * x + y * y)],
+        ])
+    }
+
+    // (w, x, y, z) of a 3×3 rotation, or None unless the matrix is one:
+    // RᵀR = I to within tol entrywise, and det R > 0 rather than a reflection
+    pub fn to_quaternion(&self, tol : T) -> Option<(T, T, T, T)> {
+        if self.rows != 3 || self.cols != 3 {
+            return None;
+        }
+
+        let r = |i : usize, j : usize| self.m[i][j];
+        let orthonormal = (0..3).all(|i| (0..3).all(|j| {
+            let dot = (0..3).fold(T::zero(), |acc, k| acc + r(k, i) * r(k, j));
+            let expected = if i == j { T::one() } else { T::zero() };
+            (dot - expected).abs() <= tol
+        }));
+        let det = r(0, 0) * (r(1, 1) * r(2, 2) - r(1, 2) * r(2, 1))
+            - r(0, 1) * (r(1, 0) * r(2, 2) - r(1, 2) * r(2, 0))
+            + r(0, 2) * (r(1, 0) * r(2, 1) - r(1, 1) * r(2, 0));
+        if !orthonormal || det <= T::zero() {
+            return None;
+        }
+
+        // Shepperd's method: recover the largest component from the
+        // diagonal, and the others from sums and differences of the off
+        // diagonal pairs divided by it, so there is never a division by a
+        // small number
+        let one = T::one();
+        let quarter = one / (one + one + one + one);
+        let trace = r(0, 0) + r(1, 1) + r(2, 2);
+        let candidates = [trace, r(0, 0), r(1, 1), r(2, 2)];
+        let largest = (1..4).fold(0, |best, k| if candidates[k] > candidates[best] { k } else { best });
+
+        let (w, x, y, z) = match largest {
+            0 => {
+                let s = (one + trace).sqrt() * (one + one);
+                (quarter * s, (r(2, 1) - r(1, 2)) / s, (r(0, 2) - r(2, 0)) / s, (r(1, 0) - r(0, 1)) / s)
+            }
+            1 => {
+                let s = (one + r(0, 0) - r(1, 1) - r(2, 2)).sqrt() * (one + one);
+                ((r(2, 1) - r(1, 2)) / s, quarter * s, (r(0, 1) + r(1, 0)) / s, (r(0, 2) + r(2, 0)) / s)
+            }
+            2 => {
+                let s = (one + r(1, 1) - r(0, 0) - r(2, 2)).sqrt() * (one + one);
+                ((r(0, 2) - r(2, 0)) / s, (r(0, 1) + r(1, 0)) / s, quarter * s, (r(1, 2) + r(2, 1)) / s)
+            }
+            _ => {
+                let s = (one + r(2, 2) - r(0, 0) - r(1, 1)).sqrt() * (one + one);
+                ((r(1, 0) - r(0, 1)) / s, (r(0, 2) + r(2, 0)) / s, (r(1, 2) + r(2, 1)) / s, quarter * s)
+            }
+        };
+
+        Some(if w < T::zero() { (-w, -x, -y, -z) } else { (w, x, y, z) })
+    }
+}
+
+// Rotations by theta radians, counterclockwise when looking back along the
+// axis towards the origin (the right hand rule), acting on column vectors
+impl<T : Float> Matrix<T> {
+    pub fn rotation2(theta : T) -> Matrix<T> {
+        let (s, c) = theta.sin_cos();
+        Matrix::from([[c, -s], [s, c]])
+    }
+
+    pub fn rotation_x(theta : T) -> Matrix<T> {
+        let (zero, one) = (T::zero(), T::one());
+        let (s, c) = theta.sin_cos();
+        Matrix::from([[one, zero, zero], [zero, c, -s], [zero, s, c]])
+    }
+
+    pub fn rotation_y(theta : T) -> Matrix<T> {
+        let (zero, one) = (T::zero(), T::one());
+        let (s, c) = theta.sin_cos();
+        Matrix::from([[c, zero, s], [zero, one, zero], [-s, zero, c]])
+    }
+
+    pub fn rotation_z(theta : T) -> Matrix<T> {
+        let (zero, one) = (T::zero(), T::one());
+        let (s, c) = theta.sin_cos();
+        Matrix::from([[c, -s, zero], [s, c, zero], [zero, zero, one]])
+    }
+
+    // Rotation about an arbitrary axis, which needn't be a unit vector but
+    // mustn't be zero
+    pub fn rotation_axis_angle(axis : [T; 3], theta : T) -> Matrix<T> {
+        let norm = axis.iter().fold(T::zero(), |acc, &a| acc + a * a).sqrt();
+        assert!(norm > T::zero());
+
+        let (s, c) = (theta / (T::one() + T::one())).sin_cos();
+        let k = s / norm;
+        Matrix::from_quaternion(c, k * axis[0], k * axis[1], k * axis[2])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{assert_close, Matrix};
+
+    #[test]
+    fn quaternion_round_trip() {
+        // a quarter turn about z takes x to y
+        let h = 0.5f64.sqrt();
+        let r = Matrix::from_quaternion(h, 0.0, 0.0, h);
+        assert!(r.is_orthogonal(1e-12));
+        let v = r.mul_vec(&[1.0,0.0,0.0]);
+        assert!(v[0].abs() < 1e-15 && (v[1] - 1.0).abs() < 1e-15);
+
+        // each branch of the extraction, including a half turn (w = 0)
+        for &(w, x, y, z) in [(0.9f64,0.1,-0.3,0.2),(0.1,0.9,0.3,-0.2),(0.0,-0.3,0.9,0.1),(-0.2,0.1,0.3,0.9)].iter() {
+            let n = (w * w + x * x + y * y + z * z).sqrt();
+            let sign = if w < 0.0 { -1.0 } else { 1.0 };
+            let q = Matrix::from_quaternion(w, x, y, z).to_quaternion(1e-12).unwrap();
+            for (a, b) in [(q.0, w), (q.1, x), (q.2, y), (q.3, z)].iter() {
+                assert!((a - sign * b / n).abs() < 1e-12);
+            }
+        }
+
+        // not rotations: a reflection, a shear and the wrong shape
+        let reflection = Matrix::from([[1.0f64,0.0,0.0],[0.0,1.0,0.0],[0.0,0.0,-1.0]]);
+        assert!(reflection.to_quaternion(1e-12).is_none());
+        assert!(Matrix::from([[1.0f64,0.5,0.0],[0.0,1.0,0.0],[0.0,0.0,1.0]]).to_quaternion(1e-12).is_none());
+        assert!(Matrix::<f64>::identity(2).to_quaternion(1e-12).is_none());
+    }
+
+    #[test]
+    fn rotation_constructors() {
+        use std::f64::consts::FRAC_PI_2;
+
+        let v = Matrix::rotation2(FRAC_PI_2).mul_vec(&[1.0,0.0]);
+        assert!(v[0].abs() < 1e-15 && (v[1] - 1.0).abs() < 1e-15);
+
+        // quarter turns cycle the axes: x about z goes to y, y about x to z,
+        // z about y to x
+        let cycled = [
+            (Matrix::rotation_z(FRAC_PI_2), [1.0,0.0,0.0], [0.0,1.0,0.0]),
+            (Matrix::rotation_x(FRAC_PI_2), [0.0,1.0,0.0], [0.0,0.0,1.0]),
+            (Matrix::rotation_y(FRAC_PI_2), [0.0,0.0,1.0], [1.0,0.0,0.0]),
+        ];
+        for (r, from, to) in cycled.iter() {
+            assert!(r.mul_vec(from).iter().zip(to.iter()).all(|(a, b)| (a - b).abs() < 1e-15));
+        }
+
+        let theta = 0.7;
+        assert_close(&Matrix::rotation_axis_angle([1.0,0.0,0.0], theta), &Matrix::rotation_x(theta), 1e-15);
+        assert_close(&Matrix::rotation_axis_angle([0.0,2.0,0.0], theta), &Matrix::rotation_y(theta), 1e-15);
+        assert_close(&Matrix::rotation_axis_angle([0.0,0.0,-1.0], -theta), &Matrix::rotation_z(theta), 1e-15);
+
+        // the axis itself is fixed
+        let r = Matrix::rotation_axis_angle([1.0f64,1.0,1.0], 2.0);
+        assert!(r.mul_vec(&[1.0,1.0,1.0]).iter().all(|x| (x - 1.0).abs() < 1e-14));
+    }
+}
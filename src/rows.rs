@@ -0,0 +1,287 @@
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::hash::Hash;
+
+#[cfg(feature = "rand")]
+use rand::{seq::{index, SliceRandom}, Rng};
+
+use crate::{Matrix, MatrixError};
+
+// Whole-row operations, for matrices used as tables of observations
+impl<T> Matrix<T> {
+    // Number of columns a new row must have; None while the matrix is 0×0,
+    // when the first row sets it
+    fn row_width(&self) -> Option<usize> {
+        if self.rows == 0 && self.cols == 0 { None } else { Some(self.cols) }
+    }
+
+    // Appends rows at the bottom. Stops at the first row of the wrong
+    // width, keeping the rows before it.
+    pub fn extend_rows<I : IntoIterator<Item=Vec<T>>>(&mut self, rows : I) -> Result<(), MatrixError> {
+        for row in rows {
+            let expected = self.row_width().unwrap_or(row.len());
+            if row.len() != expected {
+                return Err(MatrixError::RaggedRows { row : self.rows, expected, found : row.len() });
+            }
+
+            self.cols = expected;
+            self.m.push(row);
+            self.rows += 1;
+        }
+        Ok(())
+    }
+
+    // Moves every row of other onto the bottom of self, leaving other 0×0.
+    // The widths must match unless one of them is 0×0.
+    pub fn append(&mut self, other : &mut Matrix<T>) {
+        if let (Some(a), Some(b)) = (self.row_width(), other.row_width()) {
+            assert!(a == b);
+        }
+
+        self.cols = self.row_width().unwrap_or(other.cols);
+        self.rows += other.rows;
+        self.m.append(&mut other.m);
+        other.rows = 0;
+        other.cols = 0;
+    }
+
+    // Splits into rows 0..i and rows i.., without copying any entries
+    pub fn split_rows_at(mut self, i : usize) -> (Matrix<T>, Matrix<T>) {
+        assert!(i <= self.rows);
+
+        let bottom = Matrix { m : self.m.split_off(i), rows : self.rows - i, cols : self.cols };
+        self.rows = i;
+        (self, bottom)
+    }
+
+    // Keeps only the rows for which keep returns true
+    pub fn retain_rows<F : FnMut(&[T]) -> bool>(&mut self, mut keep : F) {
+        self.m.retain(|row| keep(row));
+        self.rows = self.m.len();
+    }
+
+    // Indices of the rows for which select returns true, for picking the
+    // same rows out of other matrices with slice
+    pub fn rows_where<F : FnMut(&[T]) -> bool>(&self, mut select : F) -> Vec<usize> {
+        (0..self.rows).filter(|&i| select(&self.m[i])).collect()
+    }
+
+    // Stable sort of the rows with a comparator, e.g. for floats
+    // a.sort_rows_by(|x, y| x[0].total_cmp(&y[0]))
+    pub fn sort_rows_by<F : FnMut(&[T], &[T]) -> Ordering>(&mut self, mut compare : F) {
+        self.m.sort_by(|a, b| compare(a, b));
+    }
+}
+
+impl<T : Clone> Matrix<T> {
+    // Copy of the rows for which keep returns true
+    pub fn filter_rows<F : FnMut(&[T]) -> bool>(&self, mut keep : F) -> Matrix<T> {
+        let m : Vec<Vec<T>> = self.m.iter().filter(|row| keep(row)).cloned().collect();
+        Matrix { rows : m.len(), m, cols : self.cols }
+    }
+}
+
+impl<T : Ord> Matrix<T> {
+    // Stable sort of the rows by their entries in column col
+    pub fn sort_rows_by_key(&mut self, col : usize) {
+        assert!(col < self.cols);
+        self.m.sort_by(|a, b| a[col].cmp(&b[col]));
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<T> Matrix<T> {
+    // Puts the rows in a uniformly random order, in place (Fisher–Yates).
+    // Only the row handles move, not the entries.
+    pub fn shuffle_rows<R : Rng+?Sized>(&mut self, rng : &mut R) {
+        self.m.shuffle(rng);
+    }
+
+    // Splits the rows in two by a mask, keeping their relative order
+    fn split_rows_by(self, mask : &[bool]) -> (Matrix<T>, Matrix<T>) {
+        let (mut first, mut second) = (Vec::new(), Vec::new());
+        for (row, &chosen) in self.m.into_iter().zip(mask.iter()) {
+            if chosen { first.push(row) } else { second.push(row) }
+        }
+
+        (Matrix { rows : first.len(), m : first, cols : self.cols },
+         Matrix { rows : second.len(), m : second, cols : self.cols })
+    }
+
+    // Random split for train/test sets: a fraction of the rows (rounded to
+    // the nearest row), chosen uniformly, go to the first matrix and the rest
+    // to the second, both in their original order. Also returns the indices
+    // of the rows in the first matrix, ascending.
+    pub fn partition_rows<R : Rng+?Sized>(self, fraction : f64, rng : &mut R)
+        -> (Matrix<T>, Matrix<T>, Vec<usize>) {
+        assert!((0.0..=1.0).contains(&fraction));
+
+        let count = (fraction * self.rows as f64).round() as usize;
+        let mut chosen = index::sample(rng, self.rows, count).into_vec();
+        chosen.sort_unstable();
+
+        let mut mask = vec![false ; self.rows];
+        for &i in chosen.iter() {
+            mask[i] = true;
+        }
+        let (first, second) = self.split_rows_by(&mask);
+        (first, second, chosen)
+    }
+}
+
+impl<T : Eq+Hash> Matrix<T> {
+    // Indices of the first occurrence of each distinct row, in order
+    fn first_occurrences(&self) -> Vec<usize> {
+        let mut seen = HashSet::new();
+        (0..self.rows).filter(|&i| seen.insert(&self.m[i][..])).collect()
+    }
+
+    // Removes every row equal to an earlier one, keeping the original order,
+    // and returns the (original) indices of the rows that were kept
+    pub fn dedup_rows(&mut self) -> Vec<usize> {
+        let kept = self.first_occurrences();
+
+        let mut keep = vec![false ; self.rows];
+        for &i in kept.iter() {
+            keep[i] = true;
+        }
+        let mut flags = keep.into_iter();
+        self.m.retain(|_| flags.next().unwrap());
+        self.rows = kept.len();
+
+        kept
+    }
+}
+
+impl<T : Clone+Eq+Hash> Matrix<T> {
+    // Copy with duplicate rows removed, as dedup_rows
+    pub fn unique_rows(&self) -> Matrix<T> {
+        let m : Vec<Vec<T>> = self.first_occurrences().into_iter().map(|i| self.m[i].clone()).collect();
+        Matrix { rows : m.len(), m, cols : self.cols }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Matrix, MatrixError};
+
+    #[test]
+    fn growing_by_rows() {
+        let mut a = Matrix::from(Vec::<Vec<i32>>::new());
+        a.extend_rows(vec![vec![1,2], vec![3,4]]).unwrap();
+        assert_eq!(a, Matrix::from([[1,2],[3,4]]));
+
+        assert_eq!(a.extend_rows(vec![vec![5,6], vec![7]]),
+                   Err(MatrixError::RaggedRows { row : 3, expected : 2, found : 1 }));
+        assert_eq!(a.rows(), 3);
+
+        let mut b = Matrix::from([[8,9]]);
+        a.append(&mut b);
+        assert_eq!(a, Matrix::from([[1,2],[3,4],[5,6],[8,9]]));
+        assert_eq!((b.rows(), b.cols()), (0, 0));
+
+        // an empty matrix takes the width of whatever is appended to it
+        b.append(&mut a);
+        assert_eq!(b.cols(), 2);
+        assert_eq!(b.rows(), 4);
+    }
+
+    #[test]
+    fn sorting_rows() {
+        let mut a = Matrix::from([[3,1],[1,2],[2,0],[1,1]]);
+        a.sort_rows_by_key(0);
+        assert_eq!(a, Matrix::from([[1,2],[1,1],[2,0],[3,1]]));
+
+        // lexicographic, by whole rows
+        a.sort_rows_by(|x, y| x.cmp(y));
+        assert_eq!(a, Matrix::from([[1,1],[1,2],[2,0],[3,1]]));
+
+        let mut f = Matrix::from([[0.5f64,1.0],[-2.0,3.0],[0.25,0.0]]);
+        f.sort_rows_by(|x, y| y[0].total_cmp(&x[0]));
+        assert_eq!(f.column(0), vec![0.5,0.25,-2.0]);
+    }
+
+    #[test]
+    fn filtering_rows() {
+        let mut a = Matrix::from([[1,10],[2,20],[3,30],[4,40]]);
+        let labels = Matrix::from([[0],[1],[0],[1]]);
+
+        let even = |row : &[i32]| row[0] % 2 == 0;
+        assert_eq!(a.filter_rows(even), Matrix::from([[2,20],[4,40]]));
+
+        let chosen = labels.rows_where(|row| row[0] == 1);
+        assert_eq!(chosen, vec![1,3]);
+        assert_eq!(a.slice(chosen), a.filter_rows(even));
+
+        a.retain_rows(|row| row[1] > 15);
+        assert_eq!(a, Matrix::from([[2,20],[3,30],[4,40]]));
+        a.retain_rows(|_| false);
+        assert_eq!((a.rows(), a.cols()), (0, 2));
+    }
+
+    #[test]
+    fn removing_duplicate_rows() {
+        let mut a = Matrix::from([[1,2],[3,4],[1,2],[5,6],[3,4]]);
+        assert_eq!(a.unique_rows(), Matrix::from([[1,2],[3,4],[5,6]]));
+
+        assert_eq!(a.dedup_rows(), vec![0,1,3]);
+        assert_eq!(a, Matrix::from([[1,2],[3,4],[5,6]]));
+        assert_eq!(a.dedup_rows(), vec![0,1,2]);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn shuffling_rows() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let a = Matrix::from_fn(20, 2, |i, j| (i * 2 + j) as i32);
+        let mut b = a.clone();
+        b.shuffle_rows(&mut StdRng::seed_from_u64(7));
+        assert_ne!(a, b);
+        assert!(b.rows_where(|row| row[1] == row[0] + 1).len() == 20);
+
+        let mut c = a.clone();
+        c.shuffle_rows(&mut StdRng::seed_from_u64(7));
+        assert_eq!(b, c);
+
+        b.sort_rows_by_key(0);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn splitting_rows() {
+        let a = Matrix::from([[1,2],[3,4],[5,6]]);
+
+        let (top, bottom) = a.clone().split_rows_at(1);
+        assert_eq!(top, Matrix::from([[1,2]]));
+        assert_eq!(bottom, Matrix::from([[3,4],[5,6]]));
+
+        let (all, none) = a.clone().split_rows_at(3);
+        assert_eq!(all, a);
+        assert_eq!((none.rows(), none.cols()), (0, 2));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn random_partition() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let a = Matrix::from_fn(10, 3, |i, j| (i * 3 + j) as i32);
+        let (train, test, chosen) = a.clone().partition_rows(0.75, &mut StdRng::seed_from_u64(1));
+
+        assert_eq!((train.rows(), test.rows(), train.cols()), (8, 2, 3));
+        assert_eq!(train, a.slice(chosen.clone()));
+        assert!(chosen.windows(2).all(|w| w[0] < w[1]));
+
+        let mut rejoined = train;
+        rejoined.append(&mut test.clone());
+        rejoined.sort_rows_by_key(0);
+        assert_eq!(rejoined, a);
+    }
+
+    #[test]
+    #[should_panic]
+    fn append_mismatched_widths() {
+        Matrix::from([[1,2]]).append(&mut Matrix::from([[1,2,3]]));
+    }
+}
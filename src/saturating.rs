@@ -0,0 +1,62 @@
+use num_traits::{SaturatingAdd, SaturatingMul};
+
+use crate::Matrix;
+
+// Arithmetic that clamps at the bounds of the type instead of overflowing,
+// for primitive integers and fixed-point types alike (the `fixed` crate's
+// I16F16 and friends, via its num-traits support). Saturation happens at
+// every step, so a product's accumulation order matters once a partial sum
+// clips: here it is the usual left to right over the inner index.
+impl<T : Clone+Default+SaturatingAdd+SaturatingMul> Matrix<T> {
+    pub fn add_saturating(&self, other : &Matrix<T>) -> Matrix<T> {
+        assert!(self.rows == other.rows && self.cols == other.cols);
+        Matrix::from_fn(self.rows, self.cols, |i, j| self.m[i][j].saturating_add(&other.m[i][j]))
+    }
+
+    pub fn mul_saturating(&self, other : &Matrix<T>) -> Matrix<T> {
+        assert!(self.cols == other.rows);
+        Matrix::from_fn(self.rows, other.cols, |i, j| {
+            self.m[i].iter().zip(other.m.iter())
+                .fold(T::default(), |acc, (a, row)| acc.saturating_add(&a.saturating_mul(&row[j])))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Matrix;
+
+    #[test]
+    fn saturating_arithmetic() {
+        let a = Matrix::from([[100i8,-100],[20,3]]);
+        assert_eq!(a.add_saturating(&a), Matrix::from([[127,-128],[40,6]]));
+        // each term clips before the sum: 100·100 → 127, then + (-100·20 → -128)
+        assert_eq!(a.mul_saturating(&a), Matrix::from([[-1,-128],[127,-119]]));
+
+        // without overflow it agrees with the usual product
+        let b = Matrix::from([[1i16,2],[3,4]]);
+        assert_eq!(b.mul_saturating(&b), &b * &b);
+    }
+
+    #[cfg(feature = "fixed")]
+    #[test]
+    fn fixed_point_matrices() {
+        use fixed::types::I16F16;
+
+        let f = |x : f64| I16F16::from_num(x);
+        let a = Matrix::from([[f(1.5),f(-0.25)],[f(2.0),f(0.5)]]);
+
+        // construction, add and mul all work through the generic impls
+        assert_eq!(Matrix::<I16F16>::identity(2).rows(), 2);
+        assert_eq!(&a + &a, Matrix::from([[f(3.0),f(-0.5)],[f(4.0),f(1.0)]]));
+        assert_eq!(&a * &Matrix::identity(2), a);
+        assert_eq!(&a * &a, Matrix::from([[f(1.75),f(-0.5)],[f(4.0),f(-0.25)]]));
+
+        // I16F16 tops out just below 32768
+        let big = Matrix::from([[f(200.0),f(200.0)]]);
+        let mut col = big.clone();
+        col.transpose();
+        assert_eq!(big.mul_saturating(&col), Matrix::from([[I16F16::MAX]]));
+        assert_eq!(big.add_saturating(&big), Matrix::from([[f(400.0),f(400.0)]]));
+    }
+}
@@ -0,0 +1,262 @@
+use num_traits::Float;
+
+use crate::Matrix;
+
+// Real Schur decomposition A = Q T Qᵀ: Q is orthogonal and T is upper
+// quasi-triangular, with 1×1 diagonal blocks for the real eigenvalues and
+// 2×2 blocks for the complex conjugate pairs
+#[derive(Clone,Debug,PartialEq)]
+pub struct Schur<T> {
+    pub t : Matrix<T>,
+    pub q : Matrix<T>,
+}
+
+// Householder reflector (v, β) with (I - βvvᵀ)x = αe1, for the 2 or 3
+// entries of x; None when x is zero
+fn reflector<T : Float>(x : &[T]) -> Option<(Vec<T>, T)> {
+    let norm = x.iter().fold(T::zero(), |acc, &a| acc + a * a).sqrt();
+    if norm == T::zero() {
+        return None;
+    }
+    let alpha = if x[0] > T::zero() { -norm } else { norm };
+    let mut v = x.to_vec();
+    v[0] = v[0] - alpha;
+    let vv = v.iter().fold(T::zero(), |acc, &a| acc + a * a);
+    Some((v, (T::one() + T::one()) / vv))
+}
+
+// Rows k.. (as many as v has entries) <- P · rows, over the given columns
+fn reflect_rows<T : Float>(a : &mut [Vec<T>], k : usize, v : &[T], beta : T, cols : std::ops::Range<usize>) {
+    for j in cols {
+        let s = v.iter().enumerate().fold(T::zero(), |acc, (i, &vi)| acc + vi * a[k + i][j]) * beta;
+        for (i, &vi) in v.iter().enumerate() {
+            a[k + i][j] = a[k + i][j] - s * vi;
+        }
+    }
+}
+
+// Columns k.. <- columns · P, over the given rows
+fn reflect_cols<T : Float>(a : &mut [Vec<T>], k : usize, v : &[T], beta : T, rows : std::ops::Range<usize>) {
+    for row in a[rows].iter_mut() {
+        let s = v.iter().enumerate().fold(T::zero(), |acc, (i, &vi)| acc + vi * row[k + i]) * beta;
+        for (i, &vi) in v.iter().enumerate() {
+            row[k + i] = row[k + i] - s * vi;
+        }
+    }
+}
+
+impl<T : Float> Schur<T> {
+    // Eigenvalues as (real, imaginary) pairs in diagonal order, a complex
+    // pair appearing as (re, im) then (re, -im)
+    pub fn eigenvalues(&self) -> Vec<(T, T)> {
+        let t = &self.t.m;
+        let n = self.t.rows;
+        let two = T::one() + T::one();
+
+        let mut values = Vec::with_capacity(n);
+        let mut i = 0;
+        while i < n {
+            if i + 1 < n && t[i + 1][i] != T::zero() {
+                let (a, b, c, d) = (t[i][i], t[i][i + 1], t[i + 1][i], t[i + 1][i + 1]);
+                let half = (a - d) / two;
+                let im = (-(half * half + b * c)).max(T::zero()).sqrt();
+                let re = (a + d) / two;
+                values.push((re, im));
+                values.push((re, -im));
+                i += 2;
+            } else {
+                values.push((t[i][i], T::zero()));
+                i += 1;
+            }
+        }
+        values
+    }
+}
+
+impl<T : Float> Matrix<T> {
+    // Real Schur form by Francis double-shift QR iteration on the Hessenberg
+    // form, deflating blocks off the bottom as their sub-diagonal entries
+    // become negligible. 2×2 blocks with real eigenvalues are split by a
+    // rotation, so every remaining 2×2 block holds a complex pair. Returns
+    // None in the (rare) event that the iteration fails to converge.
+    pub fn schur(&self) -> Option<Schur<T>> {
+        let (h, q) = self.hessenberg();
+        let n = self.rows;
+        let (mut h, mut q) = (h, q);
+        let eps = T::epsilon();
+
+        let mut hi = n;
+        let mut iterations = 0;
+        while hi > 0 {
+            let last = hi - 1;
+
+            // the active block runs from l to last
+            let mut l = last;
+            while l > 0 {
+                let scale = h.m[l - 1][l - 1].abs() + h.m[l][l].abs();
+                if h.m[l][l - 1].abs() <= eps * scale || h.m[l][l - 1] == T::zero() {
+                    h.m[l][l - 1] = T::zero();
+                    break;
+                }
+                l -= 1;
+            }
+
+            if l == last {
+                hi -= 1;
+                iterations = 0;
+                continue;
+            }
+            if l + 1 == last {
+                split_real_pair(&mut h, &mut q, l);
+                hi -= 2;
+                iterations = 0;
+                continue;
+            }
+
+            iterations += 1;
+            if iterations > 100 {
+                return None;
+            }
+            francis_step(&mut h.m, &mut q.m, l, last, iterations);
+        }
+
+        Some(Schur { t : h, q })
+    }
+
+    // All eigenvalues of a square matrix as (real, imaginary) pairs, from
+    // its real Schur form
+    pub fn eigenvalues(&self) -> Option<Vec<(T, T)>> {
+        Some(self.schur()?.eigenvalues())
+    }
+}
+
+// One implicit double-shift QR step on rows and columns l..=last, with the
+// shifts the eigenvalues of the trailing 2×2 block (or, every tenth
+// iteration, an exceptional shift to break cycles)
+fn francis_step<T : Float>(h : &mut [Vec<T>], q : &mut [Vec<T>], l : usize, last : usize, iterations : usize) {
+    let n = h.len();
+
+    let (mut s, mut t) = (h[last - 1][last - 1] + h[last][last],
+                          h[last - 1][last - 1] * h[last][last] - h[last - 1][last] * h[last][last - 1]);
+    if iterations.is_multiple_of(10) {
+        let w = h[last][last - 1].abs() + h[last - 1][last - 2].abs();
+        s = T::from(1.5).unwrap() * w;
+        t = w * w;
+    }
+
+    let mut x = h[l][l] * h[l][l] + h[l][l + 1] * h[l + 1][l] - s * h[l][l] + t;
+    let mut y = h[l + 1][l] * (h[l][l] + h[l + 1][l + 1] - s);
+    let mut z = h[l + 1][l] * h[l + 2][l + 1];
+
+    for k in l..(last - 1) {
+        if let Some((v, beta)) = reflector(&[x, y, z]) {
+            let first = if k > l { k - 1 } else { l };
+            reflect_rows(h, k, &v, beta, first..n);
+            reflect_cols(h, k, &v, beta, 0..(k + 4).min(last + 1));
+            reflect_cols(q, k, &v, beta, 0..n);
+            if k > l {
+                h[k + 1][k - 1] = T::zero();
+                h[k + 2][k - 1] = T::zero();
+            }
+        }
+        x = h[k + 1][k];
+        y = h[k + 2][k];
+        if k + 3 <= last {
+            z = h[k + 3][k];
+        }
+    }
+
+    if let Some((v, beta)) = reflector(&[x, y]) {
+        reflect_rows(h, last - 1, &v, beta, (last - 2)..n);
+        reflect_cols(h, last - 1, &v, beta, 0..(last + 1));
+        reflect_cols(q, last - 1, &v, beta, 0..n);
+        h[last][last - 2] = T::zero();
+    }
+}
+
+// If the 2×2 block at (p, p) has real eigenvalues, rotates it to upper
+// triangular form (an eigenvector of one of them becomes the first axis)
+fn split_real_pair<T : Float>(h : &mut Matrix<T>, q : &mut Matrix<T>, p : usize) {
+    let (a, b, c, d) = (h.m[p][p], h.m[p][p + 1], h.m[p + 1][p], h.m[p + 1][p + 1]);
+    let two = T::one() + T::one();
+    let half = (a - d) / two;
+    let disc = half * half + b * c;
+    if disc < T::zero() {
+        return;
+    }
+
+    // the eigenvalue further from d, for a well conditioned eigenvector
+    let root = disc.sqrt();
+    let lambda = (a + d) / two + if half >= T::zero() { root } else { -root };
+    let (u, w) = if (lambda - a).abs() + b.abs() >= (lambda - d).abs() + c.abs() {
+        (b, lambda - a)
+    } else {
+        (lambda - d, c)
+    };
+    if u == T::zero() && w == T::zero() {
+        return;
+    }
+
+    let (cs, sn) = Matrix::givens(u, w);
+    h.rotate_rows(p, p + 1, cs, sn);
+    h.rotate_cols(p, p + 1, cs, sn);
+    q.rotate_cols(p, p + 1, cs, sn);
+    h.m[p + 1][p] = T::zero();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{assert_close, Matrix};
+
+    fn sorted(mut values : Vec<(f64, f64)>) -> Vec<(f64, f64)> {
+        values.sort_by(|x, y| x.partial_cmp(y).unwrap());
+        values
+    }
+
+    #[test]
+    fn schur_of_rotation_and_scaling() {
+        // eigenvalues 2, 1 ± 2i and -3
+        let a = Matrix::from([[2.0f64,1.0,0.0,3.0],[0.0,1.0,-2.0,1.0],[0.0,2.0,1.0,0.5],[0.0,0.0,0.0,-3.0]]);
+        let p = Matrix::from([[1.0,2.0,0.0,1.0],[0.0,1.0,1.0,0.0],[1.0,0.0,1.0,2.0],[0.0,1.0,0.0,1.0]]);
+        let b = &(&p * &a) * &p.inverse().unwrap();
+
+        let schur = b.schur().unwrap();
+        assert!(schur.q.is_orthogonal(1e-12));
+        let mut qt = schur.q.clone();
+        qt.transpose();
+        assert_close(&(&(&schur.q * &schur.t) * &qt), &b, 1e-9);
+        for i in 2..4 {
+            for j in 0..(i - 1) {
+                assert_eq!(schur.t[(i, j)], 0.0);
+            }
+        }
+
+        let expected = [(-3.0, 0.0), (1.0, -2.0), (1.0, 2.0), (2.0, 0.0)];
+        for (l, e) in sorted(schur.eigenvalues()).iter().zip(expected.iter()) {
+            assert!((l.0 - e.0).abs() < 1e-9 && (l.1 - e.1).abs() < 1e-9, "{:?} vs {:?}", l, e);
+        }
+    }
+
+    #[test]
+    fn real_eigenvalues_of_nonsymmetric_matrix() {
+        // companion matrix of (x - 1)(x - 2)(x - 3)(x - 4)(x - 5)
+        let mut c = Matrix::new(5, 5);
+        let coefficients = [-120.0, 274.0, -225.0, 85.0, -15.0];
+        for i in 1..5 {
+            c.m[i][i - 1] = 1.0;
+        }
+        for (i, &k) in coefficients.iter().enumerate() {
+            c.m[i][4] = -k;
+        }
+
+        let schur = c.schur().unwrap();
+        assert!(schur.t.is_upper_triangular(0.0));
+        for (l, e) in sorted(schur.eigenvalues()).iter().zip(1..=5) {
+            assert!((l.0 - e as f64).abs() < 1e-8 && l.1 == 0.0);
+        }
+
+        // a plane rotation has a purely imaginary pair
+        let r = Matrix::from([[0.0f64,-1.0],[1.0,0.0]]);
+        assert_eq!(sorted(r.eigenvalues().unwrap()), vec![(0.0,-1.0),(0.0,1.0)]);
+    }
+}
@@ -0,0 +1,126 @@
+use std::ops::{Add, AddAssign, Mul};
+
+use crate::Matrix;
+
+// The operations a matrix product needs: an associative, commutative
+// addition with identity `zero`, and a multiplication distributing over it.
+// Implemented by the marker types below, and by anything else a caller
+// wants to plug into `mul_with`.
+pub trait Semiring<T> {
+    fn zero(&self) -> T;
+    fn add(&self, a : T, b : T) -> T;
+    fn mul(&self, a : &T, b : &T) -> T;
+}
+
+// Ordinary + and ×
+#[derive(Clone,Copy,Debug,Default)]
+pub struct Arithmetic;
+
+// OR and AND on bool
+#[derive(Clone,Copy,Debug,Default)]
+pub struct Boolean;
+
+// min and + on Option<T>, with None as +∞
+#[derive(Clone,Copy,Debug,Default)]
+pub struct MinPlus;
+
+// max and × on non-negative values, with zero as the identity for max
+#[derive(Clone,Copy,Debug,Default)]
+pub struct MaxTimes;
+
+impl<T : AddAssign+Clone+Default+Mul<Output=T>> Semiring<T> for Arithmetic {
+    fn zero(&self) -> T {
+        T::default()
+    }
+
+    fn add(&self, mut a : T, b : T) -> T {
+        a += b;
+        a
+    }
+
+    fn mul(&self, a : &T, b : &T) -> T {
+        a.clone() * b.clone()
+    }
+}
+
+impl Semiring<bool> for Boolean {
+    fn zero(&self) -> bool {
+        false
+    }
+
+    fn add(&self, a : bool, b : bool) -> bool {
+        a || b
+    }
+
+    fn mul(&self, a : &bool, b : &bool) -> bool {
+        *a && *b
+    }
+}
+
+impl<T : Clone+PartialOrd+Add<Output=T>> Semiring<Option<T>> for MinPlus {
+    fn zero(&self) -> Option<T> {
+        None
+    }
+
+    fn add(&self, a : Option<T>, b : Option<T>) -> Option<T> {
+        match (a, b) {
+            (Some(a), Some(b)) => Some(if b < a { b } else { a }),
+            (a, None) => a,
+            (None, b) => b,
+        }
+    }
+
+    fn mul(&self, a : &Option<T>, b : &Option<T>) -> Option<T> {
+        match (a, b) {
+            (Some(a), Some(b)) => Some(a.clone() + b.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl<T : Clone+Default+PartialOrd+Mul<Output=T>> Semiring<T> for MaxTimes {
+    fn zero(&self) -> T {
+        T::default()
+    }
+
+    fn add(&self, a : T, b : T) -> T {
+        if b > a { b } else { a }
+    }
+
+    fn mul(&self, a : &T, b : &T) -> T {
+        a.clone() * b.clone()
+    }
+}
+
+impl<T> Matrix<T> {
+    // Matrix product with the addition and multiplication of the semiring s
+    pub fn mul_with<S : Semiring<T>>(&self, rhs : &Matrix<T>, s : &S) -> Matrix<T> {
+        // can only multiply if LHS.cols == RHS.rows
+        assert!(self.cols == rhs.rows);
+
+        Matrix::from_fn(self.rows, rhs.cols, |i, j| {
+            let mut entry = s.zero();
+            for k in 0..self.cols {
+                entry = s.add(entry, s.mul(&self.m[i][k], &rhs.m[k][j]));
+            }
+            entry
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Arithmetic, MaxTimes};
+    use crate::Matrix;
+
+    #[test]
+    fn semiring_products() {
+        let a = Matrix::from([[1,2],[3,4]]);
+        let b = Matrix::from([[5,6],[7,8]]);
+        assert_eq!(a.mul_with(&b, &Arithmetic), &a * &b);
+
+        // max-times picks the most probable two-step transition
+        let p = Matrix::from([[0.5,0.5],[0.25,0.75]]);
+        assert_eq!(p.mul_with(&p, &MaxTimes), Matrix::from([[0.25,0.375],[0.1875,0.5625]]));
+    }
+}
@@ -0,0 +1,170 @@
+use std::ops::{Div, Mul, Sub};
+
+use crate::Matrix;
+
+impl<T : Clone+Default+PartialEq+Sub<Output=T>+Mul<Output=T>+Div<Output=T>> Matrix<T> {
+    // Solves LX = B by forward substitution, where L is this (lower
+    // triangular) matrix and B may have several columns. Entries above the
+    // diagonal are ignored. Returns None if there is a zero on the diagonal.
+    pub fn solve_lower_triangular(&self, b : &Matrix<T>) -> Option<Matrix<T>> {
+        assert!(self.is_square());
+        assert!(self.rows == b.rows);
+
+        let zero = T::default();
+        let mut x = b.m.clone();
+
+        for i in 0..self.rows {
+            if self.m[i][i] == zero {
+                return None;
+            }
+            for k in 0..i {
+                for j in 0..b.cols {
+                    x[i][j] = x[i][j].clone() - self.m[i][k].clone() * x[k][j].clone();
+                }
+            }
+            for j in 0..b.cols {
+                x[i][j] = x[i][j].clone() / self.m[i][i].clone();
+            }
+        }
+
+        Some(Matrix { m : x, rows : b.rows, cols : b.cols })
+    }
+
+    // Solves UX = B by backward substitution, where U is this (upper
+    // triangular) matrix. Entries below the diagonal are ignored.
+    pub fn solve_upper_triangular(&self, b : &Matrix<T>) -> Option<Matrix<T>> {
+        assert!(self.is_square());
+        assert!(self.rows == b.rows);
+
+        let zero = T::default();
+        let mut x = b.m.clone();
+
+        for i in (0..self.rows).rev() {
+            if self.m[i][i] == zero {
+                return None;
+            }
+            for k in (i + 1)..self.rows {
+                for j in 0..b.cols {
+                    x[i][j] = x[i][j].clone() - self.m[i][k].clone() * x[k][j].clone();
+                }
+            }
+            for j in 0..b.cols {
+                x[i][j] = x[i][j].clone() / self.m[i][i].clone();
+            }
+        }
+
+        Some(Matrix { m : x, rows : b.rows, cols : b.cols })
+    }
+
+    // Solves a tridiagonal system in O(n) with the Thomas algorithm, given
+    // the sub-diagonal, diagonal and super-diagonal (the off-diagonals have
+    // one entry fewer than the diagonal). No pivoting is done, so this is
+    // intended for diagonally dominant or positive-definite systems; returns
+    // None if a zero pivot turns up.
+    pub fn solve_tridiagonal_bands(sub : &[T], diag : &[T], sup : &[T], b : &Matrix<T>) -> Option<Matrix<T>> {
+        let n = diag.len();
+        assert!(b.rows == n);
+        assert!(n == 0 || (sub.len() == n - 1 && sup.len() == n - 1));
+
+        let zero = T::default();
+        let mut c : Vec<T> = Vec::with_capacity(n);
+        let mut x = b.m.clone();
+
+        for i in 0..n {
+            // eliminate the sub-diagonal entry using the previous row
+            let mut pivot = diag[i].clone();
+            if i > 0 {
+                pivot = pivot - sub[i - 1].clone() * c[i - 1].clone();
+                for j in 0..b.cols {
+                    x[i][j] = x[i][j].clone() - sub[i - 1].clone() * x[i - 1][j].clone();
+                }
+            }
+            if pivot == zero {
+                return None;
+            }
+
+            if i + 1 < n {
+                c.push(sup[i].clone() / pivot.clone());
+            }
+            for j in 0..b.cols {
+                x[i][j] = x[i][j].clone() / pivot.clone();
+            }
+        }
+
+        for i in (0..n.saturating_sub(1)).rev() {
+            for j in 0..b.cols {
+                x[i][j] = x[i][j].clone() - c[i].clone() * x[i + 1][j].clone();
+            }
+        }
+
+        Some(Matrix { m : x, rows : b.rows, cols : b.cols })
+    }
+
+    // Solves AX = B for a tridiagonal A using its three central diagonals;
+    // anything outside them is ignored
+    pub fn solve_tridiagonal(&self, b : &Matrix<T>) -> Option<Matrix<T>> {
+        assert!(self.is_square());
+
+        let n = self.rows;
+        let diag : Vec<T> = (0..n).map(|i| self.m[i][i].clone()).collect();
+        let sub : Vec<T> = (1..n).map(|i| self.m[i][i - 1].clone()).collect();
+        let sup : Vec<T> = (1..n).map(|i| self.m[i - 1][i].clone()).collect();
+
+        Matrix::solve_tridiagonal_bands(&sub, &diag, &sup, b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{assert_close, Matrix};
+
+    #[test]
+    fn basic_triangular_solves() {
+        let l = Matrix::from([[2.0,0.0,0.0],[1.0,4.0,0.0],[-1.0,2.0,5.0]]);
+        let b = Matrix::from([[2.0,4.0],[9.0,6.0],[8.0,5.0]]);
+        let x = l.solve_lower_triangular(&b).unwrap();
+        assert_eq!(x, Matrix::from([[1.0,2.0],[2.0,1.0],[1.0,1.0]]));
+
+        let mut u = l.clone();
+        u.transpose();
+        let b = Matrix::from([[2.0,-1.0],[6.0,8.0],[5.0,10.0]]);
+        let x = u.solve_upper_triangular(&b).unwrap();
+        assert_eq!(x, Matrix::from([[1.0,0.0],[1.0,1.0],[1.0,2.0]]));
+    }
+
+    #[test]
+    fn triangular_solve_after_cholesky() {
+        let a = Matrix::from([[4.0,12.0,-16.0],[12.0,37.0,-43.0],[-16.0,-43.0,98.0]]);
+        let b = Matrix::from([[1.0f64],[2.0],[3.0]]);
+
+        let l = a.cholesky().unwrap();
+        let mut lt = l.clone();
+        lt.transpose();
+        let y = l.solve_lower_triangular(&b).unwrap();
+        let x = lt.solve_upper_triangular(&y).unwrap();
+
+        assert_close(&(&a * &x), &b, 1e-10);
+    }
+
+    #[test]
+    fn singular_triangular_solve() {
+        let u = Matrix::from([[1,2],[0,0]]);
+        assert_eq!(u.solve_upper_triangular(&Matrix::from([[1],[1]])), None);
+    }
+
+    #[test]
+    fn basic_tridiagonal_solve() {
+        // the 1-D Poisson stencil
+        let a = Matrix::from([[2.0,-1.0,0.0,0.0],[-1.0,2.0,-1.0,0.0],
+                              [0.0,-1.0,2.0,-1.0],[0.0,0.0,-1.0,2.0]]);
+        assert!(a.is_tridiagonal(0.0));
+
+        let b = Matrix::from([[1.0f64,0.0],[0.0,1.0],[0.0,0.0],[1.0,0.0]]);
+        let x = a.solve_tridiagonal(&b).unwrap();
+        assert_close(&(&a * &x), &b, 1e-12);
+
+        let banded = Matrix::solve_tridiagonal_bands(&[-1.0,-1.0,-1.0], &[2.0,2.0,2.0,2.0],
+                                                     &[-1.0,-1.0,-1.0], &b).unwrap();
+        assert_eq!(banded, x);
+    }
+}
@@ -0,0 +1,317 @@
+use std::ops::{AddAssign, Mul};
+
+use crate::Matrix;
+
+// Sparse matrix in compressed sparse row (CSR) format: the column indices and
+// values of row i live in col_idx/values[row_ptr[i]..row_ptr[i + 1]], sorted
+// by column.
+#[derive(Clone,Debug,PartialEq,Eq)]
+pub struct SparseMatrix<T> {
+    pub(crate) rows : usize,
+    pub(crate) cols : usize,
+    pub(crate) row_ptr : Vec<usize>,
+    pub(crate) col_idx : Vec<usize>,
+    pub(crate) values : Vec<T>,
+}
+
+impl<T> SparseMatrix<T> {
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    // Number of explicitly stored entries
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    // Stored entry at (i, j), or None if it is an implicit zero
+    pub fn get(&self, i : usize, j : usize) -> Option<&T> {
+        let range = self.row_ptr[i]..self.row_ptr[i + 1];
+        self.col_idx[range.clone()]
+            .binary_search(&j)
+            .ok()
+            .map(|k| &self.values[range.start + k])
+    }
+
+    // Iterates over the stored (column, value) pairs of row i
+    pub fn row_entries(&self, i : usize) -> impl Iterator<Item=(usize, &T)> {
+        let range = self.row_ptr[i]..self.row_ptr[i + 1];
+        self.col_idx[range.clone()].iter().cloned().zip(self.values[range].iter())
+    }
+}
+
+impl<T : AddAssign> SparseMatrix<T> {
+    // Builds a matrix from (row, column, value) triplets in any order,
+    // summing any duplicates
+    pub fn from_triplets<I>(rows : usize, cols : usize, triplets : I) -> Self
+        where I : IntoIterator<Item=(usize, usize, T)> {
+        let mut triplets : Vec<(usize, usize, T)> = triplets.into_iter().collect();
+        for &(i, j, _) in triplets.iter() {
+            assert!(i < rows && j < cols);
+        }
+        triplets.sort_by_key(|&(i, j, _)| (i, j));
+
+        let mut row_ptr = vec![0 ; rows + 1];
+        let mut col_idx : Vec<usize> = Vec::with_capacity(triplets.len());
+        let mut values : Vec<T> = Vec::with_capacity(triplets.len());
+        let mut last = None;
+
+        for (i, j, value) in triplets {
+            if last == Some((i, j)) {
+                *values.last_mut().unwrap() += value;
+            } else {
+                row_ptr[i + 1] += 1;
+                col_idx.push(j);
+                values.push(value);
+                last = Some((i, j));
+            }
+        }
+
+        // turn per-row counts into offsets
+        for i in 0..rows {
+            row_ptr[i + 1] += row_ptr[i];
+        }
+
+        SparseMatrix { rows, cols, row_ptr, col_idx, values }
+    }
+}
+
+// Accumulates (row, column, value) triplets one at a time, e.g. during
+// finite-element assembly, and then compresses them into a SparseMatrix.
+// Entries pushed to the same position are summed.
+#[derive(Clone,Debug,PartialEq,Eq)]
+pub struct CooBuilder<T> {
+    rows : usize,
+    cols : usize,
+    triplets : Vec<(usize, usize, T)>,
+}
+
+impl<T> CooBuilder<T> {
+    pub fn new(rows : usize, cols : usize) -> Self {
+        CooBuilder { rows, cols, triplets : Vec::new() }
+    }
+
+    pub fn with_capacity(rows : usize, cols : usize, capacity : usize) -> Self {
+        CooBuilder { rows, cols, triplets : Vec::with_capacity(capacity) }
+    }
+
+    pub fn push(&mut self, i : usize, j : usize, value : T) {
+        assert!(i < self.rows && j < self.cols);
+        self.triplets.push((i, j, value));
+    }
+
+    // Number of triplets pushed so far, counting duplicates separately
+    pub fn len(&self) -> usize {
+        self.triplets.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.triplets.is_empty()
+    }
+}
+
+impl<T : AddAssign> CooBuilder<T> {
+    pub fn build(self) -> SparseMatrix<T> {
+        SparseMatrix::from_triplets(self.rows, self.cols, self.triplets)
+    }
+}
+
+impl<T : Clone+Default+PartialEq> SparseMatrix<T> {
+    // Keeps only the non-zero entries of a dense matrix
+    pub fn from_dense(other : &Matrix<T>) -> Self {
+        let zero = T::default();
+        let mut row_ptr = Vec::with_capacity(other.rows + 1);
+        let mut col_idx = Vec::new();
+        let mut values = Vec::new();
+
+        row_ptr.push(0);
+        for row in other.m.iter() {
+            for (j, x) in row.iter().enumerate() {
+                if *x != zero {
+                    col_idx.push(j);
+                    values.push(x.clone());
+                }
+            }
+            row_ptr.push(values.len());
+        }
+
+        SparseMatrix { rows : other.rows, cols : other.cols, row_ptr, col_idx, values }
+    }
+}
+
+impl<T : Clone+Default> SparseMatrix<T> {
+    pub fn to_dense(&self) -> Matrix<T> {
+        let mut matrix = Matrix::new(self.rows, self.cols);
+        for i in 0..self.rows {
+            for (j, x) in self.row_entries(i) {
+                matrix.m[i][j] = x.clone();
+            }
+        }
+        matrix
+    }
+}
+
+impl<T : AddAssign+Clone+Default+Mul<Output=T>> SparseMatrix<T> {
+    // Sparse matrix times dense vector, in O(nnz)
+    pub fn mul_vec(&self, x : &[T]) -> Vec<T> {
+        assert!(x.len() == self.cols);
+
+        (0..self.rows).map(|i| {
+            let mut total = T::default();
+            for (j, a) in self.row_entries(i) {
+                total += a.clone() * x[j].clone();
+            }
+            total
+        }).collect()
+    }
+}
+
+// Sparse × dense in O(nnz · rhs.cols)
+impl<T : AddAssign+Clone+Default+Mul<Output=T>> Mul<&Matrix<T>> for &SparseMatrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs : &Matrix<T>) -> Matrix<T> {
+        assert!(self.cols == rhs.rows);
+
+        let mut matrix = Matrix::new(self.rows, rhs.cols);
+        for i in 0..self.rows {
+            for (k, a) in self.row_entries(i) {
+                for j in 0..rhs.cols {
+                    matrix.m[i][j] += a.clone() * rhs.m[k][j].clone();
+                }
+            }
+        }
+        matrix
+    }
+}
+
+// Dense × sparse in O(self.rows · nnz)
+impl<T : AddAssign+Clone+Default+Mul<Output=T>> Mul<&SparseMatrix<T>> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs : &SparseMatrix<T>) -> Matrix<T> {
+        assert!(self.cols == rhs.rows);
+
+        let mut matrix = Matrix::new(self.rows, rhs.cols);
+        for i in 0..self.rows {
+            for k in 0..self.cols {
+                for (j, b) in rhs.row_entries(k) {
+                    matrix.m[i][j] += self.m[i][k].clone() * b.clone();
+                }
+            }
+        }
+        matrix
+    }
+}
+
+// Sparse × sparse using Gustavson's row-by-row algorithm, so the work is
+// proportional to the number of multiplications actually performed. Entries
+// which cancel to zero are kept as explicit zeros.
+impl<T : AddAssign+Clone+Mul<Output=T>> Mul<&SparseMatrix<T>> for &SparseMatrix<T> {
+    type Output = SparseMatrix<T>;
+
+    fn mul(self, rhs : &SparseMatrix<T>) -> SparseMatrix<T> {
+        assert!(self.cols == rhs.rows);
+
+        let mut row_ptr = Vec::with_capacity(self.rows + 1);
+        let mut col_idx = Vec::new();
+        let mut values = Vec::new();
+
+        // dense accumulator for the current row, plus the columns it touched
+        let mut acc : Vec<Option<T>> = vec![None ; rhs.cols];
+        let mut touched = Vec::new();
+
+        row_ptr.push(0);
+        for i in 0..self.rows {
+            for (k, a) in self.row_entries(i) {
+                for (j, b) in rhs.row_entries(k) {
+                    let product = a.clone() * b.clone();
+                    match acc[j] {
+                        Some(ref mut total) => *total += product,
+                        None => {
+                            acc[j] = Some(product);
+                            touched.push(j);
+                        }
+                    }
+                }
+            }
+
+            touched.sort_unstable();
+            for j in touched.drain(..) {
+                col_idx.push(j);
+                values.push(acc[j].take().unwrap());
+            }
+            row_ptr.push(values.len());
+        }
+
+        SparseMatrix { rows : self.rows, cols : rhs.cols, row_ptr, col_idx, values }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CooBuilder, SparseMatrix};
+    use crate::Matrix;
+
+    #[test]
+    fn sparse_from_triplets() {
+        let s = SparseMatrix::from_triplets(3, 4, vec![(2,1,5), (0,3,1), (0,0,2), (2,1,-1)]);
+
+        assert_eq!(s.nnz(), 3);
+        assert_eq!(s.get(2, 1), Some(&4));
+        assert_eq!(s.get(1, 1), None);
+        assert_eq!(s.to_dense(), Matrix::from([[2,0,0,1],[0,0,0,0],[0,4,0,0]]));
+    }
+
+    #[test]
+    fn sparse_dense_round_trip() {
+        let m = Matrix::from([[0,0,3],[1,0,0],[0,0,0],[0,7,-2]]);
+        let s = SparseMatrix::from_dense(&m);
+
+        assert_eq!(s.nnz(), 4);
+        assert_eq!(s.to_dense(), m);
+    }
+
+    #[test]
+    fn sparse_mul_vec() {
+        let m = Matrix::from([[0,0,3],[1,0,0],[0,0,0],[0,7,-2]]);
+        let s = SparseMatrix::from_dense(&m);
+        let x = Matrix::from([[1],[2],[3]]);
+
+        assert_eq!(s.mul_vec(&[1,2,3]), (&m * &x).into_row_major_vec());
+    }
+
+    #[test]
+    fn coo_builder_assembly() {
+        // assemble the 1-D stiffness matrix from per-element contributions
+        let mut builder = CooBuilder::new(4, 4);
+        for e in 0..3 {
+            builder.push(e, e, 1);
+            builder.push(e, e + 1, -1);
+            builder.push(e + 1, e, -1);
+            builder.push(e + 1, e + 1, 1);
+        }
+        assert_eq!(builder.len(), 12);
+
+        let s = builder.build();
+        assert_eq!(s.nnz(), 10);
+        assert_eq!(s.to_dense(), Matrix::from([[1,-1,0,0],[-1,2,-1,0],[0,-1,2,-1],[0,0,-1,1]]));
+    }
+
+    #[test]
+    fn sparse_products() {
+        let a = Matrix::from([[1,0,2],[0,0,0],[0,3,0],[4,0,-1]]);
+        let b = Matrix::from([[0,1],[2,0],[0,-3]]);
+        let (sa, sb) = (SparseMatrix::from_dense(&a), SparseMatrix::from_dense(&b));
+        let expected = &a * &b;
+
+        assert_eq!(&sa * &b, expected);
+        assert_eq!(&a * &sb, expected);
+        assert_eq!((&sa * &sb).to_dense(), expected);
+        assert_eq!((&sa * &sb).nnz(), 3);
+    }
+}
@@ -0,0 +1,407 @@
+use num_traits::Float;
+
+use crate::Matrix;
+
+// Thin singular value decomposition A = U Σ Vᵀ, with the singular values in
+// decreasing order and U, V having one orthonormal column per value
+#[derive(Clone,Debug,PartialEq)]
+pub struct Svd<T> {
+    pub u : Matrix<T>,
+    pub singular_values : Vec<T>,
+    pub v : Matrix<T>,
+}
+
+impl<T : Float> Svd<T> {
+    // Multiplies the factors back together
+    pub fn reconstruct(&self) -> Matrix<T> {
+        let (m, n) = (self.u.rows, self.v.rows);
+        let mut a = vec![vec![T::zero() ; n] ; m];
+        for (k, &s) in self.singular_values.iter().enumerate() {
+            for i in 0..m {
+                let us = self.u.m[i][k] * s;
+                for j in 0..n {
+                    a[i][j] = a[i][j] + us * self.v.m[j][k];
+                }
+            }
+        }
+        Matrix { m : a, rows : m, cols : n }
+    }
+
+    // Number of singular values above dimension · ε times the largest
+    fn numerical_rank(&self, dimension : usize) -> usize {
+        let largest = self.singular_values.first().cloned().unwrap_or_else(T::zero);
+        let cutoff = largest * T::epsilon() * T::from(dimension).unwrap();
+        self.singular_values.iter().filter(|&&s| s > cutoff).count()
+    }
+}
+
+fn dot<T : Float>(x : &[T], y : &[T]) -> T {
+    x.iter().zip(y.iter()).fold(T::zero(), |acc, (&a, &b)| acc + a * b)
+}
+
+// Column-major copy of a matrix
+pub(crate) fn columns<T : Clone>(a : &Matrix<T>) -> Vec<Vec<T>> {
+    (0..a.cols).map(|j| a.m.iter().map(|row| row[j].clone()).collect()).collect()
+}
+
+pub(crate) fn from_columns<T : Clone>(cols : &[Vec<T>], rows : usize) -> Matrix<T> {
+    let m = (0..rows).map(|i| cols.iter().map(|col| col[i].clone()).collect()).collect();
+    Matrix { m, rows, cols : cols.len() }
+}
+
+// Orthonormalises a list of columns in place with modified Gram-Schmidt
+// (run twice, which is enough to keep them orthogonal to working
+// precision), dropping any which are numerically dependent on the others
+pub(crate) fn orthonormalize_columns<T : Float>(cols : &mut Vec<Vec<T>>) {
+    let mut basis : Vec<Vec<T>> = Vec::with_capacity(cols.len());
+    let cutoff = T::epsilon() * T::from(100.0).unwrap();
+
+    for mut col in cols.drain(..) {
+        let original = dot(&col, &col).sqrt();
+        for _ in 0..2 {
+            for q in basis.iter() {
+                let r = dot(q, &col);
+                for (c, &qi) in col.iter_mut().zip(q.iter()) {
+                    *c = *c - r * qi;
+                }
+            }
+        }
+
+        let norm = dot(&col, &col).sqrt();
+        if norm > cutoff * original && norm > T::zero() {
+            col.iter_mut().for_each(|c| *c = *c / norm);
+            basis.push(col);
+        }
+    }
+
+    *cols = basis;
+}
+
+// Adds standard basis vectors to an orthonormal set until it has `count`
+// columns
+fn complete_basis<T : Float>(basis : &mut Vec<Vec<T>>, len : usize, count : usize) {
+    for i in 0..len {
+        if basis.len() >= count {
+            break;
+        }
+        let mut candidate : Vec<Vec<T>> = basis.clone();
+        let mut e = vec![T::zero() ; len];
+        e[i] = T::one();
+        candidate.push(e);
+        orthonormalize_columns(&mut candidate);
+        if candidate.len() > basis.len() {
+            *basis = candidate;
+        }
+    }
+}
+
+// One-sided (Hestenes) Jacobi SVD of the matrix whose columns are given,
+// assuming there are no more columns than rows. Rotates pairs of columns
+// until they are all mutually orthogonal; their norms are then the singular
+// values.
+fn jacobi_svd<T : Float>(mut u : Vec<Vec<T>>, rows : usize) -> (Vec<Vec<T>>, Vec<T>, Vec<Vec<T>>) {
+    let n = u.len();
+    let mut v : Vec<Vec<T>> = (0..n).map(|j| {
+        (0..n).map(|i| if i == j { T::one() } else { T::zero() }).collect()
+    }).collect();
+
+    let two = T::one() + T::one();
+    for _sweep in 0..60 {
+        let mut rotated = false;
+
+        for p in 0..n {
+            for q in (p + 1)..n {
+                let alpha = dot(&u[p], &u[p]);
+                let beta = dot(&u[q], &u[q]);
+                let gamma = dot(&u[p], &u[q]);
+                if gamma == T::zero() || gamma.abs() <= T::epsilon() * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+
+                let zeta = (beta - alpha) / (two * gamma);
+                let t = zeta.signum() / (zeta.abs() + (T::one() + zeta * zeta).sqrt());
+                let c = (T::one() + t * t).sqrt().recip();
+                let s = c * t;
+
+                for cols in [&mut u, &mut v].iter_mut() {
+                    for i in 0..cols[p].len() {
+                        let (x, y) = (cols[p][i], cols[q][i]);
+                        cols[p][i] = c * x - s * y;
+                        cols[q][i] = s * x + c * y;
+                    }
+                }
+            }
+        }
+
+        if !rotated {
+            break;
+        }
+    }
+
+    let mut sigma : Vec<T> = u.iter().map(|col| dot(col, col).sqrt()).collect();
+
+    // sort into decreasing order of singular value
+    let mut order : Vec<usize> = (0..n).collect();
+    order.sort_by(|&a, &b| sigma[b].partial_cmp(&sigma[a]).unwrap_or(std::cmp::Ordering::Equal));
+    let mut u : Vec<Vec<T>> = order.iter().map(|&k| u[k].clone()).collect();
+    let v = order.iter().map(|&k| v[k].clone()).collect();
+    sigma = order.iter().map(|&k| sigma[k]).collect();
+
+    // normalise the left vectors; those for (numerically) zero singular
+    // values carry no direction, so replace them by completing the basis
+    let largest = sigma.first().cloned().unwrap_or_else(T::zero);
+    let negligible = largest * T::epsilon() * T::from(rows.max(n)).unwrap();
+    let mut basis = Vec::with_capacity(n);
+    for (col, &s) in u.iter_mut().zip(sigma.iter()) {
+        if s > negligible && s > T::zero() {
+            col.iter_mut().for_each(|x| *x = *x / s);
+            basis.push(col.clone());
+        } else {
+            break;
+        }
+    }
+    let rank = basis.len();
+    complete_basis(&mut basis, rows, n);
+    u = basis;
+    for s in sigma.iter_mut().skip(rank) {
+        if *s <= negligible {
+            *s = T::zero();
+        }
+    }
+
+    (u, sigma, v)
+}
+
+// Tiny deterministic xorshift generator for the random test matrices in the
+// randomised SVD, so results are reproducible
+fn pseudo_random_columns<T : Float>(rows : usize, cols : usize) -> Vec<Vec<T>> {
+    let mut state : u64 = 0x9e37_79b9_7f4a_7c15;
+    (0..cols).map(|_| {
+        (0..rows).map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            // uniform in [-1, 1)
+            T::from((state >> 11) as f64 / (1u64 << 52) as f64 - 1.0).unwrap()
+        }).collect()
+    }).collect()
+}
+
+impl<T : Float> Matrix<T> {
+    // Full thin SVD by one-sided Jacobi rotations, which is slow but very
+    // accurate, including for the small singular values
+    pub fn svd(&self) -> Svd<T> {
+        let (u, singular_values, v) = if self.rows >= self.cols {
+            let (u, s, v) = jacobi_svd(columns(self), self.rows);
+            (from_columns(&u, self.rows), s, from_columns(&v, self.cols))
+        } else {
+            // work with Aᵀ = V Σ Uᵀ instead, whose columns are our rows
+            let (v, s, u) = jacobi_svd(self.m.clone(), self.cols);
+            (from_columns(&u, self.rows), s, from_columns(&v, self.cols))
+        };
+
+        Svd { u, singular_values, v }
+    }
+
+    // Polar decomposition A = UP of a square matrix, with U orthogonal and P
+    // symmetric positive semi-definite (definite when A is invertible).
+    // From the SVD A = WΣVᵀ, U = WVᵀ and P = VΣVᵀ. For a deformation
+    // gradient U is the rotation (or reflection, if det A < 0) and P the
+    // stretch.
+    pub fn polar(&self) -> (Matrix<T>, Matrix<T>) {
+        assert!(self.is_square());
+
+        let Svd { u : w, singular_values, v } = self.svd();
+        let n = self.rows;
+        let u = Matrix::from_fn(n, n, |i, j| dot(&w.m[i], &v.m[j]));
+        let p = Matrix::from_fn(n, n, |i, j| {
+            singular_values.iter().enumerate().fold(T::zero(), |acc, (k, &s)| acc + v.m[i][k] * s * v.m[j][k])
+        });
+
+        (u, p)
+    }
+
+    // Orthonormal basis of the null space, from the right singular vectors
+    // whose singular values are negligible (at most max(rows, cols) · ε
+    // times the largest). A full column rank matrix gives a cols × 0 result.
+    pub fn null_space(&self) -> Matrix<T> {
+        let svd = self.svd();
+        let rank = svd.numerical_rank(self.rows.max(self.cols));
+
+        // a wide matrix's thin SVD lacks some of the null directions, so
+        // complete the basis of the row space before taking what's left
+        let mut basis : Vec<Vec<T>> = columns(&svd.v).into_iter().take(rank).collect();
+        complete_basis(&mut basis, self.cols, self.cols);
+
+        from_columns(&basis[rank..], self.cols)
+    }
+
+    // Orthonormal basis of the column space (image), from the left singular
+    // vectors for the singular values null_space doesn't count as negligible
+    pub fn column_space(&self) -> Matrix<T> {
+        let svd = self.svd();
+        let rank = svd.numerical_rank(self.rows.max(self.cols));
+        let basis : Vec<Vec<T>> = columns(&svd.u).into_iter().take(rank).collect();
+
+        from_columns(&basis, self.rows)
+    }
+
+    // AᵀX for X given by columns, returning columns
+    fn transpose_mul_columns(&self, x : &[Vec<T>]) -> Vec<Vec<T>> {
+        x.iter().map(|col| {
+            let mut out = vec![T::zero() ; self.cols];
+            for (row, &c) in self.m.iter().zip(col.iter()) {
+                for (o, &a) in out.iter_mut().zip(row.iter()) {
+                    *o = *o + a * c;
+                }
+            }
+            out
+        }).collect()
+    }
+
+    // AX for X given by columns, returning columns
+    fn mul_columns(&self, x : &[Vec<T>]) -> Vec<Vec<T>> {
+        x.iter().map(|col| self.m.iter().map(|row| dot(row, col)).collect()).collect()
+    }
+
+    // Best rank-k approximation U_k Σ_k V_kᵀ, found by a randomised range
+    // finder with a couple of power iterations followed by an SVD of the
+    // much smaller projected matrix. If A has rank below k, fewer factors
+    // may come back.
+    pub fn low_rank_approx(&self, k : usize) -> Svd<T> {
+        let r = self.rows.min(self.cols);
+        assert!(k <= r);
+
+        // oversample a little to capture the tail of the spectrum
+        let l = (k + 10).min(r);
+        let mut q = self.mul_columns(&pseudo_random_columns(self.cols, l));
+        orthonormalize_columns(&mut q);
+        for _ in 0..2 {
+            let mut z = self.transpose_mul_columns(&q);
+            orthonormalize_columns(&mut z);
+            q = self.mul_columns(&z);
+            orthonormalize_columns(&mut q);
+        }
+
+        // B = QᵀA is small, and A ≈ QB
+        let b_rows : Vec<Vec<T>> = self.transpose_mul_columns(&q);
+        let b = Matrix { m : b_rows, rows : q.len(), cols : self.cols };
+        let small = b.svd();
+
+        let k = k.min(small.singular_values.len());
+        let u_small = columns(&small.u);
+        let u : Vec<Vec<T>> = u_small.iter().take(k).map(|c| {
+            (0..self.rows).map(|i| {
+                q.iter().zip(c.iter()).fold(T::zero(), |acc, (qj, &cj)| acc + qj[i] * cj)
+            }).collect()
+        }).collect();
+        let v : Vec<Vec<T>> = columns(&small.v).into_iter().take(k).collect();
+
+        Svd {
+            u : from_columns(&u, self.rows),
+            singular_values : small.singular_values[..k].to_vec(),
+            v : from_columns(&v, self.cols),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{assert_close, Matrix};
+
+    #[test]
+    fn basic_svd() {
+        let a = Matrix::from([[3.0f64,2.0,2.0],[2.0,3.0,-2.0]]);
+        let svd = a.svd();
+
+        assert!((svd.singular_values[0] - 5.0).abs() < 1e-12);
+        assert!((svd.singular_values[1] - 3.0).abs() < 1e-12);
+        assert!(svd.u.is_orthogonal(1e-12));
+        assert_close(&svd.reconstruct(), &a, 1e-12);
+
+        let mut t = a.clone();
+        t.transpose();
+        assert_close(&t.svd().reconstruct(), &t, 1e-12);
+    }
+
+    #[test]
+    fn rank_deficient_svd() {
+        let a = Matrix::from([[1.0,2.0],[2.0,4.0],[3.0,6.0]]);
+        let svd = a.svd();
+
+        assert_eq!(svd.singular_values[1], 0.0);
+        assert!(svd.u.gram().is_identity(1e-12));
+        assert_close(&svd.reconstruct(), &a, 1e-12);
+    }
+
+    #[test]
+    fn polar_decomposition() {
+        // a rotation by 30° after a stretch
+        let (c, s) = (3f64.sqrt() / 2.0, 0.5);
+        let rotation = Matrix::from([[c,-s],[s,c]]);
+        let stretch = Matrix::from([[2.0,0.5],[0.5,1.0]]);
+        let a = &rotation * &stretch;
+
+        let (u, p) = a.polar();
+        assert!(u.is_orthogonal(1e-12));
+        assert!(p.is_symmetric(1e-12) && p.is_positive_definite());
+        assert_close(&u, &rotation, 1e-12);
+        assert_close(&p, &stretch, 1e-12);
+        assert_close(&(&u * &p), &a, 1e-12);
+
+        // singular, so P is only semi-definite but U is still orthogonal
+        let (u, p) = Matrix::from([[1.0,2.0],[2.0,4.0]]).polar();
+        assert!(u.is_orthogonal(1e-12));
+        assert_close(&(&u * &p), &Matrix::from([[1.0,2.0],[2.0,4.0]]), 1e-12);
+    }
+
+    #[test]
+    fn svd_null_space() {
+        let wide = Matrix::from([[1.0,2.0,0.0,3.0],[2.0,4.0,1.0,8.0],[3.0,6.0,1.0,11.0]]);
+        let n = wide.null_space();
+
+        assert_eq!((n.rows, n.cols), (4, 2));
+        assert!(n.gram().is_identity(1e-12));
+        assert_close(&(&wide * &n), &Matrix::new(3, 2), 1e-12);
+
+        let tall = Matrix::from([[1.0,2.0],[2.0,4.0],[3.0,6.0]]);
+        let n = tall.null_space();
+        assert_close(&(&tall * &n), &Matrix::new(3, 1), 1e-12);
+        assert_eq!(Matrix::<f64>::identity(3).null_space().cols, 0);
+    }
+
+    #[test]
+    fn svd_column_space() {
+        let a = Matrix::from([[1.0,2.0,0.0,3.0],[2.0,4.0,1.0,8.0],[3.0,6.0,1.0,11.0]]);
+        let c = a.column_space();
+        assert_eq!((c.rows, c.cols), (3, 2));
+        assert!(c.gram().is_identity(1e-12));
+
+        // every column of A is its own projection onto the column space
+        let mut ct = c.clone();
+        ct.transpose();
+        assert_close(&(&c * &(&ct * &a)), &a, 1e-12);
+    }
+
+    #[test]
+    fn low_rank_approximation() {
+        // an exactly rank-2, 30×20 matrix
+        let m = Matrix::from((0..30).map(|i| {
+            (0..20).map(|j| {
+                let (x, y) = (i as f64, j as f64);
+                (x + 1.0) * (y - 3.0) + 0.5 * (x * x).sin() * (y + 1.0).cos()
+            }).collect()
+        }).collect::<Vec<Vec<f64>>>());
+
+        let full = m.svd();
+        let approx = m.low_rank_approx(2);
+
+        assert_eq!(approx.singular_values.len(), 2);
+        for k in 0..2 {
+            let rel = (approx.singular_values[k] - full.singular_values[k]).abs() / full.singular_values[k];
+            assert!(rel < 1e-10);
+        }
+        assert_close(&approx.reconstruct(), &m, 1e-8);
+    }
+}
@@ -0,0 +1,204 @@
+use std::ops::{AddAssign, Index, IndexMut, Mul};
+
+use crate::Matrix;
+
+// A stack of depth equally-shaped rows × cols matrices, stored contiguously
+// slice after slice (each slice row-major), for time series of matrices,
+// multi-channel images and the like
+#[derive(Clone,Debug,PartialEq,Eq)]
+pub struct Tensor3<T> {
+    data : Vec<T>,
+    depth : usize,
+    rows : usize,
+    cols : usize,
+}
+
+// Borrowed, read-only view of one slice of a Tensor3
+#[derive(Clone,Copy,Debug,PartialEq,Eq)]
+pub struct MatrixView<'a, T> {
+    data : &'a [T],
+    rows : usize,
+    cols : usize,
+}
+
+impl<'a, T> MatrixView<'a, T> {
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn row(&self, i : usize) -> &'a [T] {
+        assert!(i < self.rows);
+        &self.data[i * self.cols..(i + 1) * self.cols]
+    }
+}
+
+impl<T : Clone> MatrixView<'_, T> {
+    pub fn to_matrix(&self) -> Matrix<T> {
+        Matrix::from_fn(self.rows, self.cols, |i, j| self.data[i * self.cols + j].clone())
+    }
+}
+
+impl<T> Index<(usize,usize)> for MatrixView<'_, T> {
+    type Output = T;
+
+    fn index(&self, (i, j) : (usize, usize)) -> &T {
+        assert!(i < self.rows && j < self.cols);
+        &self.data[i * self.cols + j]
+    }
+}
+
+impl<T : Clone+Default> Tensor3<T> {
+    pub fn new(depth : usize, rows : usize, cols : usize) -> Self {
+        Tensor3 { data : vec![T::default() ; depth * rows * cols], depth, rows, cols }
+    }
+}
+
+impl<T> Tensor3<T> {
+    // Stacks matrices, which must all have the same shape
+    pub fn from_matrices(matrices : Vec<Matrix<T>>) -> Self {
+        let (rows, cols) = matrices.first().map_or((0, 0), |m| (m.rows, m.cols));
+        assert!(matrices.iter().all(|m| m.rows == rows && m.cols == cols));
+
+        let depth = matrices.len();
+        let data = matrices.into_iter().flat_map(|m| m.into_row_major_vec()).collect();
+        Tensor3 { data, depth, rows, cols }
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    // (depth, rows, cols)
+    pub fn shape(&self) -> (usize, usize, usize) {
+        (self.depth, self.rows, self.cols)
+    }
+
+    // View of slice k, without copying
+    pub fn slice_matrix(&self, k : usize) -> MatrixView<'_, T> {
+        assert!(k < self.depth);
+
+        let size = self.rows * self.cols;
+        MatrixView { data : &self.data[k * size..(k + 1) * size], rows : self.rows, cols : self.cols }
+    }
+
+    // Applies f to every slice and stacks the results, which must all have
+    // the same shape (not necessarily that of the input)
+    pub fn map_slices<U, F>(&self, mut f : F) -> Tensor3<U>
+        where F : FnMut(MatrixView<'_, T>) -> Matrix<U> {
+        Tensor3::from_matrices((0..self.depth).map(|k| f(self.slice_matrix(k))).collect())
+    }
+
+    // Splits back into one matrix per slice
+    pub fn into_matrices(self) -> Vec<Matrix<T>> {
+        let (rows, cols) = (self.rows, self.cols);
+        let mut entries = self.data.into_iter();
+
+        (0..self.depth).map(|_| {
+            let m = (0..rows).map(|_| entries.by_ref().take(cols).collect()).collect();
+            Matrix { m, rows, cols }
+        }).collect()
+    }
+
+    fn offset(&self, (k, i, j) : (usize, usize, usize)) -> usize {
+        assert!(k < self.depth && i < self.rows && j < self.cols);
+        (k * self.rows + i) * self.cols + j
+    }
+}
+
+impl<T : AddAssign+Clone+Default+Mul<Output=T>> Tensor3<T> {
+    // Batched product: slice k of the result is slice k of self times slice k of other
+    pub fn matmul(&self, other : &Tensor3<T>) -> Tensor3<T> {
+        assert!(self.depth == other.depth);
+        assert!(self.cols == other.rows);
+
+        let mut result = Tensor3::new(self.depth, self.rows, other.cols);
+        for k in 0..self.depth {
+            let (a, b) = (self.slice_matrix(k), other.slice_matrix(k));
+            for i in 0..self.rows {
+                let out = (k * self.rows + i) * other.cols;
+                for (l, x) in a.row(i).iter().enumerate() {
+                    for (j, y) in b.row(l).iter().enumerate() {
+                        result.data[out + j] += x.clone() * y.clone();
+                    }
+                }
+            }
+        }
+
+        result
+    }
+}
+
+// t[(k, i, j)] is entry (i, j) of slice k
+impl<T> Index<(usize,usize,usize)> for Tensor3<T> {
+    type Output = T;
+
+    fn index(&self, ix : (usize, usize, usize)) -> &T {
+        &self.data[self.offset(ix)]
+    }
+}
+
+impl<T> IndexMut<(usize,usize,usize)> for Tensor3<T> {
+    fn index_mut(&mut self, ix : (usize, usize, usize)) -> &mut T {
+        let offset = self.offset(ix);
+        &mut self.data[offset]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Tensor3;
+    use crate::Matrix;
+
+    fn stack() -> Tensor3<i32> {
+        Tensor3::from_matrices(vec![
+            Matrix::from([[1,2],[3,4],[5,6]]),
+            Matrix::from([[0,1],[1,0],[2,2]]),
+        ])
+    }
+
+    #[test]
+    fn tensor_slices() {
+        let mut t = stack();
+        assert_eq!(t.shape(), (2, 3, 2));
+        assert_eq!(t[(1, 2, 0)], 2);
+
+        let view = t.slice_matrix(0);
+        assert_eq!(view.row(1), [3,4]);
+        assert_eq!(view[(2, 1)], 6);
+        assert_eq!(view.to_matrix(), Matrix::from([[1,2],[3,4],[5,6]]));
+
+        t[(0, 0, 0)] = 9;
+        let sums = t.map_slices(|s| Matrix::from(vec![(0..s.rows()).map(|i| s.row(i).iter().sum()).collect()]));
+        assert_eq!(sums.shape(), (2, 1, 3));
+        assert_eq!(sums.slice_matrix(0).to_matrix(), Matrix::from([[11,7,11]]));
+
+        assert_eq!(t.into_matrices()[1], Matrix::from([[0,1],[1,0],[2,2]]));
+    }
+
+    #[test]
+    fn tensor_batched_matmul() {
+        let t = stack();
+        let rhs = Tensor3::from_matrices(vec![
+            Matrix::from([[1,0,2],[0,1,1]]),
+            Matrix::from([[2,1,0],[1,1,1]]),
+        ]);
+
+        let product = t.matmul(&rhs).into_matrices();
+        let (lhs, rhs) = (t.into_matrices(), rhs.into_matrices());
+        for k in 0..2 {
+            assert_eq!(product[k], &lhs[k] * &rhs[k]);
+        }
+    }
+}
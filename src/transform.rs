@@ -0,0 +1,306 @@
+use std::ops::AddAssign;
+
+use num_traits::Float;
+
+use crate::Matrix;
+
+// Homogeneous transforms: an (n+1)×(n+1) matrix acting on n-dimensional
+// points (x, 1) and directions (x, 0), so that translations compose with
+// linear maps by ordinary multiplication. b * a applies a first.
+impl<T : Float> Matrix<T> {
+    // Translation by offset: 3×3 for a 2D offset, 4×4 for a 3D one
+    pub fn translation(offset : &[T]) -> Matrix<T> {
+        let n = offset.len();
+        Matrix::from_fn(n + 1, n + 1, |i, j| {
+            if i == j { T::one() } else if j == n { offset[i] } else { T::zero() }
+        })
+    }
+
+    // Scaling along each axis by the given factors, about the origin
+    pub fn scaling(factors : &[T]) -> Matrix<T> {
+        let n = factors.len();
+        Matrix::from_fn(n + 1, n + 1, |i, j| {
+            if i != j { T::zero() } else if i == n { T::one() } else { factors[i] }
+        })
+    }
+
+    // Embeds an n×n linear map, e.g. a rotation, as an (n+1)×(n+1) transform
+    pub fn homogeneous(&self) -> Matrix<T> {
+        assert!(self.is_square());
+        let n = self.rows;
+        Matrix::from_fn(n + 1, n + 1, |i, j| {
+            if i < n && j < n { self.m[i][j] } else if i == j { T::one() } else { T::zero() }
+        })
+    }
+
+    // Applies the transform to a point, dividing through by the resulting
+    // homogeneous coordinate (which is 1 unless the last row is projective)
+    pub fn transform_point(&self, p : &[T]) -> Vec<T> {
+        let mut x = self.homogeneous_product(p, T::one());
+        let w = x.pop().unwrap();
+        x.iter_mut().for_each(|v| *v = *v / w);
+        x
+    }
+
+    // Applies the transform to a direction, which translation leaves alone
+    pub fn transform_vector(&self, v : &[T]) -> Vec<T> {
+        let mut x = self.homogeneous_product(v, T::zero());
+        x.pop();
+        x
+    }
+
+    fn homogeneous_product(&self, p : &[T], w : T) -> Vec<T> {
+        assert!(self.is_square() && self.rows == p.len() + 1);
+        self.m.iter()
+            .map(|row| row.iter().zip(p.iter()).fold(row[p.len()] * w, |acc, (&a, &x)| acc + a * x))
+            .collect()
+    }
+}
+
+// Which way the camera looks in view space
+#[derive(Clone,Copy,Debug,Default,PartialEq,Eq)]
+pub enum Handedness {
+    // down -z, as in OpenGL
+    #[default]
+    Right,
+    // down +z, as in Direct3D
+    Left,
+}
+
+// The interval that near..far is mapped to in normalised device coordinates
+#[derive(Clone,Copy,Debug,Default,PartialEq,Eq)]
+pub enum DepthRange {
+    // OpenGL
+    #[default]
+    MinusOneToOne,
+    // Direct3D, Metal and Vulkan
+    ZeroToOne,
+}
+
+// Conventions for the projection constructors; the default is OpenGL's
+#[derive(Clone,Copy,Debug,Default,PartialEq,Eq)]
+pub struct ClipSpace {
+    pub handedness : Handedness,
+    pub depth : DepthRange,
+}
+
+impl ClipSpace {
+    // the sign of z in front of the camera
+    fn forward<T : Float>(&self) -> T {
+        match self.handedness {
+            Handedness::Right => -T::one(),
+            Handedness::Left => T::one(),
+        }
+    }
+}
+
+// 4×4 projections to clip space, for use with transform_point. Distances
+// near and far are positive, measured in front of the camera.
+impl<T : Float> Matrix<T> {
+    // Perspective projection with vertical field of view fov_y (radians) and
+    // width / height aspect ratio
+    pub fn perspective(fov_y : T, aspect : T, near : T, far : T, clip : ClipSpace) -> Matrix<T> {
+        assert!(near > T::zero() && far > near && aspect > T::zero());
+
+        let (zero, two) = (T::zero(), T::one() + T::one());
+        let f = (fov_y / two).tan().recip();
+        let s : T = clip.forward();
+        // depth along the view direction is d = s z, and ndc depth is a + b / d
+        let (a, b) = match clip.depth {
+            DepthRange::MinusOneToOne => ((far + near) / (far - near), -two * far * near / (far - near)),
+            DepthRange::ZeroToOne => (far / (far - near), -far * near / (far - near)),
+        };
+
+        Matrix::from([
+            [f / aspect, zero, zero, zero],
+            [zero, f, zero, zero],
+            [zero, zero, s * a, b],
+            [zero, zero, s, zero],
+        ])
+    }
+
+    // Orthographic projection of the box [left, right] × [bottom, top] ×
+    // [near, far] onto the clip cube
+    pub fn orthographic(left : T, right : T, bottom : T, top : T, near : T, far : T, clip : ClipSpace) -> Matrix<T> {
+        assert!(left != right && bottom != top && near != far);
+
+        let (zero, one) = (T::zero(), T::one());
+        let two = one + one;
+        let s : T = clip.forward();
+        let (a, b) = match clip.depth {
+            DepthRange::MinusOneToOne => (two / (far - near), -(far + near) / (far - near)),
+            DepthRange::ZeroToOne => (one / (far - near), -near / (far - near)),
+        };
+
+        Matrix::from([
+            [two / (right - left), zero, zero, -(right + left) / (right - left)],
+            [zero, two / (top - bottom), zero, -(top + bottom) / (top - bottom)],
+            [zero, zero, s * a, b],
+            [zero, zero, zero, one],
+        ])
+    }
+}
+
+fn cross<T : Float>(a : [T; 3], b : [T; 3]) -> [T; 3] {
+    [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]]
+}
+
+fn dot<T : Float>(a : [T; 3], b : [T; 3]) -> T {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn normalize<T : Float>(a : [T; 3]) -> [T; 3] {
+    let norm = dot(a, a).sqrt();
+    assert!(norm > T::zero());
+    [a[0] / norm, a[1] / norm, a[2] / norm]
+}
+
+impl<T : Float> Matrix<T> {
+    // 4×4 view matrix of a camera at eye looking towards target, taking
+    // world coordinates to view space: eye goes to the origin, target onto
+    // the forward axis of the handedness, and up into the upper half of the
+    // yz-plane. Panics if eye = target or up is parallel to the view
+    // direction.
+    pub fn look_at(eye : [T; 3], target : [T; 3], up : [T; 3], handedness : Handedness) -> Matrix<T> {
+        let f = normalize([target[0] - eye[0], target[1] - eye[1], target[2] - eye[2]]);
+        let (s, back) = match handedness {
+            Handedness::Right => (normalize(cross(f, up)), [-f[0], -f[1], -f[2]]),
+            Handedness::Left => (normalize(cross(up, f)), f),
+        };
+        let u = match handedness {
+            Handedness::Right => cross(s, f),
+            Handedness::Left => cross(f, s),
+        };
+
+        let (zero, one) = (T::zero(), T::one());
+        Matrix::from([
+            [s[0], s[1], s[2], -dot(s, eye)],
+            [u[0], u[1], u[2], -dot(u, eye)],
+            [back[0], back[1], back[2], -dot(back, eye)],
+            [zero, zero, zero, one],
+        ])
+    }
+}
+
+impl<T : Float+AddAssign+Default> Matrix<T> {
+    // transform_point for every row of an n×d matrix of points, at once: the
+    // points are stacked with a column of ones and multiplied by the
+    // transform's transpose in a single mul_transpose, then divided through
+    pub fn transform_points(&self, points : &Matrix<T>) -> Matrix<T> {
+        let d = points.cols;
+        assert!(self.is_square() && self.rows == d + 1);
+
+        let homogeneous = Matrix::from_fn(points.rows, d + 1, |i, j| if j < d { points.m[i][j] } else { T::one() });
+        let product = homogeneous.mul_transpose(self);
+
+        Matrix::from_fn(points.rows, d, |i, j| product.m[i][j] / product.m[i][d])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ClipSpace, DepthRange, Handedness};
+    use crate::{assert_close, Matrix};
+
+    #[test]
+    fn translation_and_scaling() {
+        let t = Matrix::translation(&[1.0f64,-2.0,3.0]);
+        let s = Matrix::scaling(&[2.0,2.0,0.5]);
+        assert_eq!((t.rows(), s.cols()), (4, 4));
+
+        assert_eq!(t.transform_point(&[1.0,1.0,1.0]), [2.0,-1.0,4.0]);
+        assert_eq!(t.transform_vector(&[1.0,1.0,1.0]), [1.0,1.0,1.0]);
+
+        // scale, then translate
+        let both = &t * &s;
+        assert_eq!(both.transform_point(&[1.0,1.0,2.0]), [3.0,0.0,4.0]);
+        assert_eq!(both.transform_vector(&[1.0,1.0,2.0]), [2.0,2.0,1.0]);
+
+        // 2D, with a rotation composed in, and undone by the inverse
+        let close = |a : Vec<f64>, b : [f64; 2]| a.iter().zip(b.iter()).all(|(x, y)| (x - y).abs() < 1e-15);
+        let r = Matrix::rotation2(std::f64::consts::FRAC_PI_2).homogeneous();
+        let m = &Matrix::translation(&[5.0,0.0]) * &r;
+        assert!(close(m.transform_point(&[1.0,0.0]), [5.0,1.0]));
+        assert!(close(m.inverse().unwrap().transform_point(&[5.0,1.0]), [1.0,0.0]));
+
+        // a projective last row triggers the divide
+        let mut p = Matrix::<f64>::identity(3);
+        p.m[2][0] = 1.0;
+        assert_eq!(p.transform_point(&[1.0,4.0]), [0.5,2.0]);
+    }
+
+    #[test]
+    fn projections() {
+        let close = |a : Vec<f64>, b : [f64; 3]| a.iter().zip(b.iter()).all(|(x, y)| (x - y).abs() < 1e-12);
+        let fov = std::f64::consts::FRAC_PI_2;
+
+        // OpenGL: the frustum edge at depth 2 is at x = ±2 aspect, y = ±2
+        let gl = Matrix::perspective(fov, 2.0, 1.0, 10.0, ClipSpace::default());
+        assert!(close(gl.transform_point(&[0.0,0.0,-1.0]), [0.0,0.0,-1.0]));
+        assert!(close(gl.transform_point(&[0.0,0.0,-10.0]), [0.0,0.0,1.0]));
+        assert!(close(gl.transform_point(&[4.0,-2.0,-2.0]), [1.0,-1.0,1.0 / 9.0]));
+
+        let d3d = ClipSpace { handedness : Handedness::Left, depth : DepthRange::ZeroToOne };
+        let p = Matrix::perspective(fov, 1.0, 0.5, 100.0, d3d);
+        assert!(close(p.transform_point(&[0.0,0.0,0.5]), [0.0,0.0,0.0]));
+        assert!(close(p.transform_point(&[0.0,0.0,100.0]), [0.0,0.0,1.0]));
+        assert!(close(p.transform_point(&[1.0,1.0,1.0]), [1.0,1.0,p.transform_point(&[0.0,0.0,1.0])[2]]));
+
+        let o = Matrix::orthographic(-2.0, 2.0, 0.0, 1.0, 1.0, 5.0, ClipSpace::default());
+        assert!(close(o.transform_point(&[-2.0,0.0,-1.0]), [-1.0,-1.0,-1.0]));
+        assert!(close(o.transform_point(&[2.0,1.0,-5.0]), [1.0,1.0,1.0]));
+
+        let o = Matrix::orthographic(-2.0, 2.0, 0.0, 1.0, 1.0, 5.0, d3d);
+        assert!(close(o.transform_point(&[0.0,0.5,1.0]), [0.0,0.0,0.0]));
+        assert!(close(o.transform_point(&[0.0,0.5,3.0]), [0.0,0.0,0.5]));
+    }
+
+    #[test]
+    fn look_at_view() {
+        use std::f64::consts::FRAC_PI_2;
+
+        let up = [0.0,1.0,0.0];
+
+        // the default camera, moved back along z
+        let v = Matrix::look_at([0.0,0.0,5.0], [0.0,0.0,0.0], up, Handedness::Right);
+        assert_close(&v, &Matrix::translation(&[0.0,0.0,-5.0]), 1e-12);
+
+        // looking down -x from x = 5 is a quarter turn about y after moving
+        // the eye to the origin
+        let v = Matrix::look_at([5.0,0.0,0.0], [0.0,0.0,0.0], up, Handedness::Right);
+        let expected = &Matrix::rotation_y(-FRAC_PI_2).homogeneous() * &Matrix::translation(&[-5.0,0.0,0.0]);
+        assert_close(&v, &expected, 1e-12);
+
+        // left handed cameras look down +z, so in view space the target is
+        // in front with positive z, and up stays up
+        let (eye, target) = ([1.0,2.0,3.0], [4.0,-2.0,3.0]);
+        let v = Matrix::look_at(eye, target, up, Handedness::Left);
+        let t = v.transform_point(&target);
+        assert!(t[0].abs() < 1e-12 && t[1].abs() < 1e-12 && (t[2] - 5.0).abs() < 1e-12);
+        assert!(v.transform_point(&eye).iter().all(|x| x.abs() < 1e-12));
+        assert!(v.transform_vector(&up)[1] > 0.0);
+
+        let linear = Matrix::from_fn(3, 3, |i, j| v[(i, j)]);
+        assert!(linear.is_orthogonal(1e-12) && linear.determinant() > 0.0);
+    }
+
+    #[test]
+    fn batch_point_transform() {
+        let points = Matrix::from([[0.5f64,0.0,-2.0],[1.0,2.0,3.0],[-1.0,0.5,4.0],[2.0,-3.0,-1.0]]);
+        let m = &(&Matrix::translation(&[1.0,-1.0,2.0]) * &Matrix::rotation_z(0.3).homogeneous()) * &Matrix::scaling(&[2.0,1.0,0.5]);
+        let p = Matrix::perspective(1.0, 1.5, 0.1, 50.0, ClipSpace::default());
+
+        for t in [m, p].iter() {
+            let all = t.transform_points(&points);
+            assert_eq!((all.rows(), all.cols()), (4, 3));
+            for i in 0..4 {
+                let one = t.transform_point(points.row(i));
+                assert!(one.iter().zip(all.row(i).iter()).all(|(a, b)| (a - b).abs() < 1e-12));
+            }
+        }
+
+        let square = Matrix::from([[0.0f64,0.0],[1.0,0.0],[1.0,1.0],[0.0,1.0]]);
+        let moved = Matrix::translation(&[2.0,3.0]).transform_points(&square);
+        assert_eq!(moved, Matrix::from([[2.0,3.0],[3.0,3.0],[3.0,4.0],[2.0,4.0]]));
+    }
+}
@@ -0,0 +1,94 @@
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::Matrix;
+
+// Width of the column bands transposes work through. Each band builds
+// BLOCK output rows at once from a BLOCK-wide strip of every input row,
+// so reads stay sequential and the rows being written stay in cache.
+const BLOCK : usize = 64;
+
+fn band(b : usize, cols : usize) -> std::ops::Range<usize> {
+    b * BLOCK..cols.min((b + 1) * BLOCK)
+}
+
+impl<T> Matrix<T> {
+    // Transposes in place. Entries are moved, not cloned, and every output
+    // row is allocated once at its final size.
+    pub fn transpose(&mut self) {
+        let (rows, cols) = (self.rows, self.cols);
+        let mut sources : Vec<_> = std::mem::take(&mut self.m).into_iter().map(Vec::into_iter).collect();
+
+        let mut m = Vec::with_capacity(cols);
+        for b in 0..cols.div_ceil(BLOCK) {
+            let mut outputs : Vec<Vec<T>> = band(b, cols).map(|_| Vec::with_capacity(rows)).collect();
+            for source in sources.iter_mut() {
+                for (output, x) in outputs.iter_mut().zip(source.by_ref()) {
+                    output.push(x);
+                }
+            }
+            m.append(&mut outputs);
+        }
+
+        self.m = m;
+        // swap row <-> column count
+        std::mem::swap(&mut self.rows, &mut self.cols);
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T : Clone+Send+Sync> Matrix<T> {
+    // transpose, building the column bands on the rayon thread pool
+    pub fn transpose_par(&mut self) {
+        let (rows, cols) = (self.rows, self.cols);
+        let source = &self.m;
+
+        let m = (0..cols.div_ceil(BLOCK)).into_par_iter().flat_map_iter(|b| {
+            let range = band(b, cols);
+            let mut outputs : Vec<Vec<T>> = range.clone().map(|_| Vec::with_capacity(rows)).collect();
+            for row in source.iter() {
+                for (output, x) in outputs.iter_mut().zip(row[range.clone()].iter()) {
+                    output.push(x.clone());
+                }
+            }
+            outputs
+        }).collect();
+
+        self.m = m;
+        std::mem::swap(&mut self.rows, &mut self.cols);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Matrix;
+
+    // large enough to span several bands, with a ragged last one
+    fn wide() -> Matrix<String> {
+        Matrix::from((0..5).map(|i| (0..150).map(|j| format!("{},{}", i, j)).collect()).collect::<Vec<Vec<_>>>())
+    }
+
+    #[test]
+    fn blocked_transpose() {
+        let mut a = wide();
+        a.transpose();
+        assert_eq!((a.rows(), a.cols()), (150, 5));
+        assert!((0..150).all(|j| (0..5).all(|i| a[(j, i)] == format!("{},{}", i, j))));
+
+        a.transpose();
+        assert_eq!(a, wide());
+
+        let mut empty = Matrix::<i32>::new(0, 3);
+        empty.transpose();
+        assert_eq!((empty.rows(), empty.cols()), (3, 0));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn parallel_transpose() {
+        let (mut a, mut b) = (wide(), wide());
+        a.transpose();
+        b.transpose_par();
+        assert_eq!(a, b);
+    }
+}